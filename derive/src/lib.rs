@@ -0,0 +1,163 @@
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Lit, Meta, NestedMeta, Variant};
+
+// Implements `seed_bootstrap::dropdown::DropdownItems` for enums with unit variants.
+// Each variant becomes a button item, or a link item when it has an `href`:
+//
+// #[derive(Clone, DropdownItems)]
+// enum Fruit {
+//     #[dropdown(title = "Green apple")]
+//     Apple,
+//     #[dropdown(divider, href = "/pears", disabled)]
+//     Pear,
+// }
+#[proc_macro_derive(DropdownItems, attributes(dropdown))]
+pub fn derive_dropdown_items(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match dropdown_items(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn dropdown_items(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let variants = match &input.data {
+        Data::Enum(data) => &data.variants,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "DropdownItems can be derived only for enums",
+            ))
+        }
+    };
+    let items = variants
+        .iter()
+        .map(variant_items)
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::seed_bootstrap::dropdown::DropdownItems
+            for #name #type_generics #where_clause
+        {
+            fn dropdown_items<Ms: 'static>() -> Vec<::seed_bootstrap::dropdown::Item<Ms, Self>> {
+                vec![#(#items)*]
+            }
+        }
+    })
+}
+
+// ------ VariantOptions ------
+
+struct VariantOptions {
+    title: Option<String>,
+    href: Option<String>,
+    divider: bool,
+    disabled: bool,
+}
+
+impl VariantOptions {
+    fn parse(variant: &Variant) -> syn::Result<Self> {
+        let mut options = Self {
+            title: None,
+            href: None,
+            divider: false,
+            disabled: false,
+        };
+        for attr in variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("dropdown"))
+        {
+            let list = match attr.parse_meta()? {
+                Meta::List(list) => list,
+                meta => return Err(syn::Error::new_spanned(meta, "expected #[dropdown(...)]")),
+            };
+            for nested in list.nested {
+                match nested {
+                    NestedMeta::Meta(Meta::NameValue(name_value)) => {
+                        let value = match &name_value.lit {
+                            Lit::Str(value) => value.value(),
+                            lit => return Err(syn::Error::new_spanned(lit, "expected a string")),
+                        };
+                        if name_value.path.is_ident("title") {
+                            options.title = Some(value);
+                        } else if name_value.path.is_ident("href") {
+                            options.href = Some(value);
+                        } else {
+                            return Err(unknown_option(&name_value.path));
+                        }
+                    }
+                    NestedMeta::Meta(Meta::Path(path)) => {
+                        if path.is_ident("divider") {
+                            options.divider = true;
+                        } else if path.is_ident("disabled") {
+                            options.disabled = true;
+                        } else {
+                            return Err(unknown_option(&path));
+                        }
+                    }
+                    nested => return Err(syn::Error::new_spanned(nested, "unknown option")),
+                }
+            }
+        }
+        Ok(options)
+    }
+}
+
+fn unknown_option(path: &syn::Path) -> syn::Error {
+    syn::Error::new_spanned(
+        path,
+        "unknown option, expected `title`, `href`, `divider` or `disabled`",
+    )
+}
+
+fn variant_items(variant: &Variant) -> syn::Result<TokenStream2> {
+    if !matches!(variant.fields, Fields::Unit) {
+        return Err(syn::Error::new_spanned(
+            variant,
+            "DropdownItems supports only unit variants",
+        ));
+    }
+    let options = VariantOptions::parse(variant)?;
+    let ident = &variant.ident;
+    let title = options
+        .title
+        .unwrap_or_else(|| split_camel_case(&ident.to_string()));
+    let disabled = options.disabled;
+
+    let item = match options.href {
+        Some(href) => quote! {
+            ::seed_bootstrap::dropdown::Item::a(#title, Self::#ident, #href)
+        },
+        None => quote! {
+            ::seed_bootstrap::dropdown::Item::button(#title, Self::#ident)
+        },
+    };
+    let divider = if options.divider {
+        quote! { ::seed_bootstrap::dropdown::Item::divider(), }
+    } else {
+        quote! {}
+    };
+    Ok(quote! {
+        #divider
+        #item.disabled(#disabled),
+    })
+}
+
+// `SomethingElse` => `Something else`
+fn split_camel_case(ident: &str) -> String {
+    let mut title = String::new();
+    for (index, c) in ident.chars().enumerate() {
+        if index > 0 && c.is_uppercase() {
+            title.push(' ');
+            title.extend(c.to_lowercase());
+        } else {
+            title.push(c);
+        }
+    }
+    title
+}
@@ -48,6 +48,7 @@ impl Route {
                     page::Page::ButtonGroups => "button-groups",
                     page::Page::Dropdowns => "dropdowns",
                     page::Page::Navbars => "navbars",
+                    page::Page::Forms => "forms",
                 };
                 url = url.add_path_part(slug);
             }
@@ -73,6 +74,7 @@ impl Route {
                 Some("button-groups") => Self::Components(page::Page::ButtonGroups),
                 Some("dropdowns") => Self::Components(page::Page::Dropdowns),
                 Some("navbars") => Self::Components(page::Page::Navbars),
+                Some("forms") => Self::Components(page::Page::Forms),
                 _ => Self::Components(page::Page::default()),
             },
             _ => Self::Unknown(url),
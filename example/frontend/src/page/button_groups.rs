@@ -2,7 +2,7 @@ use super::{DropdownID, ExampleBox, Model, Msg};
 use seed::{prelude::*, *};
 use seed_bootstrap::button::Button;
 use seed_bootstrap::button_group::ButtonGroup;
-use seed_bootstrap::dropdown::{Dropdown, Item};
+use seed_bootstrap::dropdown::{Context, Dropdown, Item};
 
 pub fn view(model: &Model) -> Node<Msg> {
     div![
@@ -80,63 +80,51 @@ r#"ButtonGroup::new("Toolbar with button groups")
         ExampleBox::new("Nesting")
             .content(div![
                 ButtonGroup::new("Button group with nested dropdown")
-                    .content(vec![
+                    .content(nodes![
                         Button::new("1").view(),
                         Button::new("2").view(),
-                        ButtonGroup::default()
-                            .content(
-                                Dropdown::new("Dropdown ")
-                                    .items(vec![Item::a("Dropdown link", (), "#"), Item::a("Dropdown link", (), "#")])
-                                    .add_on_item_click(|event, _| { event.prevent_default(); Msg::NoOp })
-                                    .view(&model.dropdowns[&DropdownID::Nested], |msg| Msg::DropdownMsg(msg, DropdownID::Nested))
-                            ).view(),
+                        Dropdown::new("Dropdown ")
+                            .items(vec![Item::a("Dropdown link", (), "#"), Item::a("Dropdown link", (), "#")])
+                            .add_on_item_click(|event, _| { event.prevent_default(); Msg::NoOp })
+                            .view_in(Context::ButtonGroup, &model.dropdowns[&DropdownID::Nested], model.dropdowns.to_msg(&DropdownID::Nested)),
                     ])
             ])
             .code(
 r##"ButtonGroup::new("Button group with nested dropdown")
-    .content(vec![
+    .content(nodes![
         Button::new("1").view(),
         Button::new("2").view(),
-        ButtonGroup::default()
-            .content(
-                Dropdown::new("Dropdown ")
-                    .items(vec![Item::a("Dropdown link", (), "#"), Item::a("Dropdown link", (), "#")])
-                    .add_on_item_click(|event, _| { event.prevent_default(); Msg::NoOp })
-                    .view(&model.dropdowns[&DropdownID::Nested], |msg| Msg::DropdownMsg(msg, DropdownID::Nested))
-            ).view(),
+        Dropdown::new("Dropdown ")
+            .items(vec![Item::a("Dropdown link", (), "#"), Item::a("Dropdown link", (), "#")])
+            .add_on_item_click(|event, _| { event.prevent_default(); Msg::NoOp })
+            .view_in(Context::ButtonGroup, &model.dropdowns[&DropdownID::Nested], model.dropdowns.to_msg(&DropdownID::Nested)),
     ])"##
             ),
         ExampleBox::new("Vertical variation")
             .content(div![
                 ButtonGroup::new("Button group with nested dropdown - vertical")
                     .vertical()
-                    .content(vec![
+                    .content(nodes![
                         Button::new("Button").secondary().view(),
                         Button::new("Button").secondary().view(),
-                        ButtonGroup::default()
-                            .content(
-                                Dropdown::new("Dropdown ")
-                                    .items(vec![Item::a("Dropdown link", (), "#"), Item::a("Dropdown link", (), "#")])
-                                    .update_toggle(|toggle| toggle.secondary())
-                                    .add_on_item_click(|event, _| { event.prevent_default(); Msg::NoOp })
-                                    .view(&model.dropdowns[&DropdownID::NestedVertical], |msg| Msg::DropdownMsg(msg, DropdownID::NestedVertical))
-                            ).view(),
+                        Dropdown::new("Dropdown ")
+                            .items(vec![Item::a("Dropdown link", (), "#"), Item::a("Dropdown link", (), "#")])
+                            .update_toggle(|toggle| toggle.secondary())
+                            .add_on_item_click(|event, _| { event.prevent_default(); Msg::NoOp })
+                            .view_in(Context::ButtonGroup, &model.dropdowns[&DropdownID::NestedVertical], model.dropdowns.to_msg(&DropdownID::NestedVertical)),
                     ])
             ])
             .code(
 r##"ButtonGroup::new("Button group with nested dropdown - vertical")
     .vertical()
-    .content(vec![
+    .content(nodes![
         Button::new("Button").secondary().view(),
         Button::new("Button").secondary().view(),
-        ButtonGroup::default()
-            .content(
-                Dropdown::new("Dropdown ")
-                    .items(vec![Item::a("Dropdown link", (), "#"), Item::a("Dropdown link", (), "#")])
-                    .update_toggle(|toggle| toggle.secondary())
-                    .add_on_item_click(|event, _| { event.prevent_default(); Msg::NoOp })
-                    .view(&model.dropdowns[&DropdownID::NestedVertical], |msg| Msg::DropdownMsg(msg, DropdownID::NestedVertical))
-            ).view(),
+        Dropdown::new("Dropdown ")
+            .items(vec![Item::a("Dropdown link", (), "#"), Item::a("Dropdown link", (), "#")])
+            .update_toggle(|toggle| toggle.secondary())
+            .add_on_item_click(|event, _| { event.prevent_default(); Msg::NoOp })
+            .view_in(Context::ButtonGroup, &model.dropdowns[&DropdownID::NestedVertical], model.dropdowns.to_msg(&DropdownID::NestedVertical)),
     ])"##
             ),
     ]
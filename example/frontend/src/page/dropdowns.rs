@@ -2,7 +2,9 @@ use super::{DropdownID, ExampleBox, Model, Msg};
 use seed::{prelude::*, *};
 use seed_bootstrap::button::{self, Button};
 use seed_bootstrap::button_group::ButtonGroup;
-use seed_bootstrap::dropdown::{Dropdown, Item};
+use seed_bootstrap::dropdown::{
+    Align, Breakpoint, Context, Direction, Dropdown, DropdownItems, Item, Placement,
+};
 
 pub fn view(model: &Model) -> Node<Msg> {
     div![
@@ -30,7 +32,7 @@ pub fn view(model: &Model) -> Node<Msg> {
                         Item::button("Another action", ()),
                         Item::button("Something else here", ())
                     ])
-                    .view(&model.dropdowns[&DropdownID::MenuButton], |msg| Msg::DropdownMsg(msg, DropdownID::MenuButton)),
+                    .view(&model.dropdowns[&DropdownID::MenuButton], model.dropdowns.to_msg(&DropdownID::MenuButton)),
             ])
             .code(
 r#"pub fn init(orders: &mut impl Orders<Msg>) -> Model {
@@ -72,7 +74,7 @@ Dropdown::new("Dropdown button ")
                         Item::button("Something else here", ()),
                     ])
                     .update_toggle(|toggle| toggle.a("#", button::Role::Button))
-                    .view(&model.dropdowns[&DropdownID::MenuLink], |msg| Msg::DropdownMsg(msg, DropdownID::MenuLink)),
+                    .view(&model.dropdowns[&DropdownID::MenuLink], model.dropdowns.to_msg(&DropdownID::MenuLink)),
                 ])
             .code(
 r##"Dropdown::new("Dropdown link ")
@@ -82,7 +84,7 @@ r##"Dropdown::new("Dropdown link ")
         Item::button("Something else here", ()),
     ])
     .update_toggle(|toggle| toggle.a("#", button::Role::Button))
-    .view(&model.dropdowns[&DropdownID::MenuLink], |msg| Msg::DropdownMsg(msg, DropdownID::MenuLink)),"##
+    .view(&model.dropdowns[&DropdownID::MenuLink], model.dropdowns.to_msg(&DropdownID::MenuLink)),"##
             ),
         ExampleBox::new("Single button - Styles")
             .content(div![
@@ -96,7 +98,7 @@ r##"Dropdown::new("Dropdown link ")
                                 Item::divider(),
                                 Item::button("Separated button", ()),
                             ])
-                            .view(&model.dropdowns[&DropdownID::Primary], |msg| Msg::DropdownMsg(msg, DropdownID::Primary)),
+                            .view(&model.dropdowns[&DropdownID::Primary], model.dropdowns.to_msg(&DropdownID::Primary)),
                         plain!["\u{00a0}"],
                         Dropdown::new("Secondary")
                             .items(vec![
@@ -107,7 +109,7 @@ r##"Dropdown::new("Dropdown link ")
                                 Item::button("Separated button", ()),
                             ])
                             .update_toggle(|toggle| toggle.secondary())
-                            .view(&model.dropdowns[&DropdownID::Secondary], |msg| Msg::DropdownMsg(msg, DropdownID::Secondary)),
+                            .view(&model.dropdowns[&DropdownID::Secondary], model.dropdowns.to_msg(&DropdownID::Secondary)),
                         plain!["\u{00a0}"],
                         Dropdown::new("Success")
                             .items(vec![
@@ -118,7 +120,7 @@ r##"Dropdown::new("Dropdown link ")
                                 Item::button("Separated button", ()),
                             ])
                             .update_toggle(|toggle| toggle.success())
-                            .view(&model.dropdowns[&DropdownID::Success], |msg| Msg::DropdownMsg(msg, DropdownID::Success)),
+                            .view(&model.dropdowns[&DropdownID::Success], model.dropdowns.to_msg(&DropdownID::Success)),
                         plain!["\u{00a0}"],
                         Dropdown::new("Info")
                             .items(vec![
@@ -129,7 +131,7 @@ r##"Dropdown::new("Dropdown link ")
                                 Item::button("Separated button", ()),
                             ])
                             .update_toggle(|toggle| toggle.info())
-                            .view(&model.dropdowns[&DropdownID::Info], |msg| Msg::DropdownMsg(msg, DropdownID::Info)),
+                            .view(&model.dropdowns[&DropdownID::Info], model.dropdowns.to_msg(&DropdownID::Info)),
                         plain!["\u{00a0}"],
                         Dropdown::new("Warning")
                             .items(vec![
@@ -140,7 +142,7 @@ r##"Dropdown::new("Dropdown link ")
                                 Item::button("Separated button", ()),
                             ])
                             .update_toggle(|toggle| toggle.warning())
-                            .view(&model.dropdowns[&DropdownID::Warning], |msg| Msg::DropdownMsg(msg, DropdownID::Warning)),
+                            .view(&model.dropdowns[&DropdownID::Warning], model.dropdowns.to_msg(&DropdownID::Warning)),
                         plain!["\u{00a0}"],
                         Dropdown::new("Danger")
                             .items(vec![
@@ -151,7 +153,7 @@ r##"Dropdown::new("Dropdown link ")
                                 Item::button("Separated button", ()),
                             ])
                             .update_toggle(|toggle| toggle.danger())
-                            .view(&model.dropdowns[&DropdownID::Danger], |msg| Msg::DropdownMsg(msg, DropdownID::Danger)),
+                            .view(&model.dropdowns[&DropdownID::Danger], model.dropdowns.to_msg(&DropdownID::Danger)),
                         plain!["\u{00a0}"],
                         Dropdown::new("Link")
                             .items(vec![
@@ -162,7 +164,7 @@ r##"Dropdown::new("Dropdown link ")
                                 Item::button("Separated button", ()),
                             ])
                             .update_toggle(|toggle| toggle.link())
-                            .view(&model.dropdowns[&DropdownID::Link], |msg| Msg::DropdownMsg(msg, DropdownID::Link)),
+                            .view(&model.dropdowns[&DropdownID::Link], model.dropdowns.to_msg(&DropdownID::Link)),
                     ])
             ])
             .code(
@@ -175,7 +177,7 @@ r#"Dropdown::new("Danger")
         Item::button("Separated button", ()),
     ])
     .update_toggle(|toggle| toggle.danger())
-    .view(&model.dropdowns[&DropdownID::Danger], |msg| Msg::DropdownMsg(msg, DropdownID::Danger)),"#
+    .view(&model.dropdowns[&DropdownID::Danger], model.dropdowns.to_msg(&DropdownID::Danger)),"#
             ),
         ExampleBox::new("Split button")
             .content(div![
@@ -190,10 +192,10 @@ r#"Dropdown::new("Danger")
                                 Item::divider(),
                                 Item::button("Separated button", ()),
                             ])
-                            .view_in_split_button(
+                            .view_in(
+                                Context::SplitButton("Toggle dropdown".into()),
                                 &model.dropdowns[&DropdownID::SplitButton],
-                                |msg| Msg::DropdownMsg(msg, DropdownID::SplitButton),
-                                "Toggle dropdown"
+                                model.dropdowns.to_msg(&DropdownID::SplitButton),
                             ),
                     ]).view()
             ])
@@ -209,12 +211,340 @@ r#"ButtonGroup::default()
                 Item::divider(),
                 Item::button("Separated button", ()),
             ])
-            .view_in_split_button(
+            .view_in(
+                Context::SplitButton("Toggle dropdown".into()),
                 &model.dropdowns[&DropdownID::SplitButton],
-                |msg| Msg::DropdownMsg(msg, DropdownID::SplitButton),
-                "Toggle dropdown"
+                model.dropdowns.to_msg(&DropdownID::SplitButton),
             ),
     ]).view()"#
             ),
+        ExampleBox::new("Placement")
+            .content(div![
+                C!["d-flex", "justify-content-between"],
+                Dropdown::new("Open upward")
+                    .items(vec![
+                        Item::button("Action", ()),
+                        Item::button("Another action", ()),
+                    ])
+                    .placement(Placement::TopStart)
+                    .view(&model.dropdowns[&DropdownID::PlacementTopStart], model.dropdowns.to_msg(&DropdownID::PlacementTopStart)),
+                Dropdown::new("Right-aligned")
+                    .items(vec![
+                        Item::button("Action", ()),
+                        Item::button("Another action", ()),
+                    ])
+                    .placement(Placement::BottomEnd)
+                    .offset(0., 8.)
+                    .update_toggle(|toggle| toggle.secondary())
+                    .view(&model.dropdowns[&DropdownID::PlacementBottomEnd], model.dropdowns.to_msg(&DropdownID::PlacementBottomEnd)),
+            ])
+            .code(
+r#"Dropdown::new("Open upward")
+    .items(vec![
+        Item::button("Action", ()),
+        Item::button("Another action", ()),
+    ])
+    .placement(Placement::TopStart)
+    .view(&model.dropdowns[&DropdownID::PlacementTopStart], model.dropdowns.to_msg(&DropdownID::PlacementTopStart)),
+Dropdown::new("Right-aligned")
+    .items(vec![
+        Item::button("Action", ()),
+        Item::button("Another action", ()),
+    ])
+    .placement(Placement::BottomEnd)
+    .offset(0., 8.)
+    .update_toggle(|toggle| toggle.secondary())
+    .view(&model.dropdowns[&DropdownID::PlacementBottomEnd], model.dropdowns.to_msg(&DropdownID::PlacementBottomEnd)),"#
+            ),
+        ExampleBox::new("Menu items")
+            .content(div![
+                Dropdown::new("Menu items")
+                    .items(vec![
+                        Item::header("Dropdown header"),
+                        Item::button("Active action", ()).active(true),
+                        Item::button("Disabled action", ()).disabled(true),
+                        Item::a("Link", (), "#"),
+                        Item::divider(),
+                        Item::text("Dropdown item text"),
+                        Item::custom(form![
+                            C!["px-4", "py-2"],
+                            input![C!["form-control"], attrs! {At::Type => "email", At::Placeholder => "email@example.com"}],
+                        ]),
+                    ])
+                    .view(&model.dropdowns[&DropdownID::MenuItems], model.dropdowns.to_msg(&DropdownID::MenuItems)),
+            ])
+            .code(
+r##"Dropdown::new("Menu items")
+    .items(vec![
+        Item::header("Dropdown header"),
+        Item::button("Active action", ()).active(true),
+        Item::button("Disabled action", ()).disabled(true),
+        Item::a("Link", (), "#"),
+        Item::divider(),
+        Item::text("Dropdown item text"),
+        Item::custom(form![
+            C!["px-4", "py-2"],
+            input![C!["form-control"], attrs! {At::Type => "email", At::Placeholder => "email@example.com"}],
+        ]),
+    ])
+    .view(&model.dropdowns[&DropdownID::MenuItems], model.dropdowns.to_msg(&DropdownID::MenuItems)),"##
+            ),
+        ExampleBox::new("Submenus")
+            .content(div![
+                Dropdown::new("Submenus")
+                    .items(vec![
+                        Item::button("Action", ()),
+                        Item::submenu("More actions", vec![
+                            Item::button("Another action", ()),
+                            Item::submenu("Even more", vec![
+                                Item::button("Deep action", ()),
+                                Item::button("Deeper action", ()),
+                            ]),
+                        ]),
+                        Item::submenu("Disabled submenu", vec![]).disabled(true),
+                    ])
+                    .view(&model.dropdowns[&DropdownID::Submenus], model.dropdowns.to_msg(&DropdownID::Submenus)),
+            ])
+            .code(
+r##"Dropdown::new("Submenus")
+    .items(vec![
+        Item::button("Action", ()),
+        Item::submenu("More actions", vec![
+            Item::button("Another action", ()),
+            Item::submenu("Even more", vec![
+                Item::button("Deep action", ()),
+                Item::button("Deeper action", ()),
+            ]),
+        ]),
+        Item::submenu("Disabled submenu", vec![]).disabled(true),
+    ])
+    .view(&model.dropdowns[&DropdownID::Submenus], model.dropdowns.to_msg(&DropdownID::Submenus)),"##
+            ),
+        ExampleBox::new("Searchable")
+            .content(div![
+                Dropdown::new("Searchable")
+                    .searchable(true)
+                    .search_placeholder("Search countries")
+                    .no_results_text("No matching countries")
+                    .items(
+                        ["Argentina", "Australia", "Brazil", "Canada", "Denmark", "Egypt", "Finland", "France",
+                         "Germany", "India", "Japan", "Kenya", "Mexico", "Norway", "South Africa", "Spain"]
+                            .iter()
+                            .map(|country| Item::button(*country, ()))
+                            .collect(),
+                    )
+                    .view(&model.dropdowns[&DropdownID::Searchable], model.dropdowns.to_msg(&DropdownID::Searchable)),
+            ])
+            .code(
+r##"Dropdown::new("Searchable")
+    .searchable(true)
+    .search_placeholder("Search countries")
+    .no_results_text("No matching countries")
+    .items(
+        ["Argentina", "Australia", "Brazil", "Canada", "Denmark", "Egypt", "Finland", "France",
+         "Germany", "India", "Japan", "Kenya", "Mexico", "Norway", "South Africa", "Spain"]
+            .iter()
+            .map(|country| Item::button(*country, ()))
+            .collect(),
+    )
+    .view(&model.dropdowns[&DropdownID::Searchable], model.dropdowns.to_msg(&DropdownID::Searchable)),"##
+            ),
+        ExampleBox::new("Multi-select")
+            .content(div![
+                Dropdown::new("Languages")
+                    .multi_select(model.multi_selected.clone())
+                    .add_on_selection_change(Msg::MultiSelectChanged)
+                    .items(
+                        ["Elm", "Haskell", "OCaml", "Rust", "TypeScript"]
+                            .iter()
+                            .map(|language| Item::button(*language, *language))
+                            .collect(),
+                    )
+                    .view(&model.dropdowns[&DropdownID::MultiSelect], model.dropdowns.to_msg(&DropdownID::MultiSelect)),
+            ])
+            .code(
+r##"Dropdown::new("Languages")
+    .multi_select(model.multi_selected.clone())
+    .add_on_selection_change(Msg::MultiSelectChanged)
+    .items(
+        ["Elm", "Haskell", "OCaml", "Rust", "TypeScript"]
+            .iter()
+            .map(|language| Item::button(*language, *language))
+            .collect(),
+    )
+    .view(&model.dropdowns[&DropdownID::MultiSelect], model.dropdowns.to_msg(&DropdownID::MultiSelect)),"##
+            ),
+        ExampleBox::new("Directions")
+            .content(div![
+                C!["d-flex"],
+                Dropdown::new("Dropup")
+                    .direction(Direction::Up)
+                    .items(vec![Item::button("Action", ()), Item::button("Another action", ())])
+                    .update_toggle(|toggle| toggle.secondary())
+                    .view(&model.dropdowns[&DropdownID::DropUp], model.dropdowns.to_msg(&DropdownID::DropUp)),
+                plain!["\u{00a0}"],
+                Dropdown::new("Dropright")
+                    .direction(Direction::Right)
+                    .items(vec![Item::button("Action", ()), Item::button("Another action", ())])
+                    .update_toggle(|toggle| toggle.secondary())
+                    .view(&model.dropdowns[&DropdownID::DropRight], model.dropdowns.to_msg(&DropdownID::DropRight)),
+                plain!["\u{00a0}"],
+                Dropdown::new("Dropleft")
+                    .direction(Direction::Left)
+                    .items(vec![Item::button("Action", ()), Item::button("Another action", ())])
+                    .update_toggle(|toggle| toggle.secondary())
+                    .view(&model.dropdowns[&DropdownID::DropLeft], model.dropdowns.to_msg(&DropdownID::DropLeft)),
+            ])
+            .code(
+r##"Dropdown::new("Dropup")
+    .direction(Direction::Up)
+    .items(vec![Item::button("Action", ()), Item::button("Another action", ())])
+    .update_toggle(|toggle| toggle.secondary())
+    .view(&model.dropdowns[&DropdownID::DropUp], model.dropdowns.to_msg(&DropdownID::DropUp)),
+Dropdown::new("Dropright")
+    .direction(Direction::Right)
+    .items(vec![Item::button("Action", ()), Item::button("Another action", ())])
+    .update_toggle(|toggle| toggle.secondary())
+    .view(&model.dropdowns[&DropdownID::DropRight], model.dropdowns.to_msg(&DropdownID::DropRight)),
+Dropdown::new("Dropleft")
+    .direction(Direction::Left)
+    .items(vec![Item::button("Action", ()), Item::button("Another action", ())])
+    .update_toggle(|toggle| toggle.secondary())
+    .view(&model.dropdowns[&DropdownID::DropLeft], model.dropdowns.to_msg(&DropdownID::DropLeft)),"##
+            ),
+        ExampleBox::new("Menu alignment")
+            .content(div![
+                C!["d-flex"],
+                Dropdown::new("Right-aligned menu")
+                    .align(Align::Right)
+                    .items(vec![Item::button("Action", ()), Item::button("Another action", ())])
+                    .update_toggle(|toggle| toggle.secondary())
+                    .view(&model.dropdowns[&DropdownID::AlignRight], model.dropdowns.to_msg(&DropdownID::AlignRight)),
+                plain!["\u{00a0}"],
+                Dropdown::new("Left, right-aligned on large screens")
+                    .responsive_align(Breakpoint::Lg, Align::Right)
+                    .items(vec![Item::button("Action", ()), Item::button("Another action", ())])
+                    .update_toggle(|toggle| toggle.secondary())
+                    .view(&model.dropdowns[&DropdownID::ResponsiveAlign], model.dropdowns.to_msg(&DropdownID::ResponsiveAlign)),
+            ])
+            .code(
+r##"Dropdown::new("Right-aligned menu")
+    .align(Align::Right)
+    .items(vec![Item::button("Action", ()), Item::button("Another action", ())])
+    .update_toggle(|toggle| toggle.secondary())
+    .view(&model.dropdowns[&DropdownID::AlignRight], model.dropdowns.to_msg(&DropdownID::AlignRight)),
+Dropdown::new("Left, right-aligned on large screens")
+    .responsive_align(Breakpoint::Lg, Align::Right)
+    .items(vec![Item::button("Action", ()), Item::button("Another action", ())])
+    .update_toggle(|toggle| toggle.secondary())
+    .view(&model.dropdowns[&DropdownID::ResponsiveAlign], model.dropdowns.to_msg(&DropdownID::ResponsiveAlign)),"##
+            ),
+        ExampleBox::new("Input group")
+            .content(div![
+                div![
+                    C!["input-group"],
+                    Dropdown::new("Search in")
+                        .items(vec![Item::button("Titles", ()), Item::button("Authors", ()), Item::button("Everything", ())])
+                        .update_toggle(|toggle| toggle.secondary().outline())
+                        .view_in(Context::InputGroupPrepend, &model.dropdowns[&DropdownID::InputGroup], model.dropdowns.to_msg(&DropdownID::InputGroup)),
+                    input![C!["form-control"], attrs! {At::Type => "text", At::from("aria-label") => "Search"}],
+                ]
+            ])
+            .code(
+r##"div![
+    C!["input-group"],
+    Dropdown::new("Search in")
+        .items(vec![Item::button("Titles", ()), Item::button("Authors", ()), Item::button("Everything", ())])
+        .update_toggle(|toggle| toggle.secondary().outline())
+        .view_in(Context::InputGroupPrepend, &model.dropdowns[&DropdownID::InputGroup], model.dropdowns.to_msg(&DropdownID::InputGroup)),
+    input![C!["form-control"], attrs! {At::Type => "text", At::from("aria-label") => "Search"}],
+]"##
+            ),
+        ExampleBox::new("Dynamic instances")
+            .content(div![
+                table![
+                    C!["table", "table-sm"],
+                    tbody![
+                        model.row_dropdowns.keys().map(|row| tr![
+                            td![C!["align-middle"], format!("Row {}", row)],
+                            td![
+                                C!["text-right"],
+                                model.row_dropdowns.view(row, |dropdown_model| {
+                                    Dropdown::new("Actions")
+                                        .items(vec![Item::button("Duplicate", false), Item::button("Remove", true)])
+                                        .update_toggle(|toggle| toggle.secondary().small())
+                                        .add_on_item_click({
+                                            let row = *row;
+                                            move |_, remove| if remove { Msg::RemoveRow(row) } else { Msg::AddRow }
+                                        })
+                                        .view(dropdown_model, model.row_dropdowns.to_msg(row))
+                                }),
+                            ],
+                        ]),
+                    ],
+                ],
+                Button::new("Add row").secondary().add_on_click(|_| Msg::AddRow).view(),
+            ])
+            .code(
+r##"// init
+row_dropdowns: Instances::init(Msg::RowDropdownMsg, 1..=3, orders, |_, orders| {
+    dropdown::init(orders)
+}),
+
+// update
+Msg::RowDropdownMsg(msg, row) => model.row_dropdowns.update(msg, row, orders),
+Msg::AddRow => {
+    model.row_dropdowns.add(model.next_row, orders, |_, orders| dropdown::init(orders));
+    model.next_row += 1;
+}
+Msg::RemoveRow(row) => {
+    model.row_dropdowns.remove(&row);
+}
+
+// view
+model.row_dropdowns.keys().map(|row| tr![
+    td![format!("Row {}", row)],
+    td![model.row_dropdowns.view(row, |dropdown_model| {
+        Dropdown::new("Actions")
+            .items(vec![Item::button("Duplicate", false), Item::button("Remove", true)])
+            .add_on_item_click({
+                let row = *row;
+                move |_, remove| if remove { Msg::RemoveRow(row) } else { Msg::AddRow }
+            })
+            .view(dropdown_model, model.row_dropdowns.to_msg(row))
+    })],
+]),"##
+            ),
+        ExampleBox::new("Derived items")
+            .content(div![
+                Dropdown::new("Sort by")
+                    .items(SortBy::dropdown_items())
+                    .view(&model.dropdowns[&DropdownID::Derived], model.dropdowns.to_msg(&DropdownID::Derived)),
+            ])
+            .code(
+r##"#[derive(Clone, Copy, DropdownItems)]
+enum SortBy {
+    Name,
+    #[dropdown(title = "Date modified")]
+    Modified,
+    FileSize,
+    #[dropdown(divider, href = "#settings", disabled)]
+    CustomOrder,
+}
+
+Dropdown::new("Sort by")
+    .items(SortBy::dropdown_items())
+    .view(&model.dropdowns[&DropdownID::Derived], model.dropdowns.to_msg(&DropdownID::Derived)),"##
+            ),
     ]
 }
+
+#[derive(Clone, Copy, DropdownItems)]
+enum SortBy {
+    Name,
+    #[dropdown(title = "Date modified")]
+    Modified,
+    FileSize,
+    #[dropdown(divider, href = "#settings", disabled)]
+    CustomOrder,
+}
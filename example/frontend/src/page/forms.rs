@@ -0,0 +1,144 @@
+use super::{ExampleBox, Model, Msg};
+use seed::{prelude::*, *};
+use seed_bootstrap::file_upload::FileUpload;
+use seed_bootstrap::tag_input::TagInput;
+use seed_bootstrap::typeahead::Typeahead;
+use std::borrow::Cow;
+
+const LANGUAGES: &[&str] = &[
+    "Ada",
+    "C",
+    "C#",
+    "C++",
+    "Clojure",
+    "Elixir",
+    "Elm",
+    "Erlang",
+    "F#",
+    "Go",
+    "Haskell",
+    "Java",
+    "JavaScript",
+    "Kotlin",
+    "Lua",
+    "OCaml",
+    "Perl",
+    "PHP",
+    "Python",
+    "Ruby",
+    "Rust",
+    "Scala",
+    "Swift",
+    "TypeScript",
+    "Zig",
+];
+
+pub fn search_languages(query: &str) -> Vec<&'static str> {
+    let query = query.to_lowercase();
+    LANGUAGES
+        .iter()
+        .copied()
+        .filter(|language| language.to_lowercase().contains(&query))
+        .collect()
+}
+
+pub fn validate_email(email: &str) -> Result<(), Cow<'static, str>> {
+    match email.find('@') {
+        Some(at) if at > 0 && email[at..].contains('.') => Ok(()),
+        _ => Err(format!("\"{}\" is not a valid email address.", email).into()),
+    }
+}
+
+pub fn view(model: &Model) -> Node<Msg> {
+    div![
+        C!["pt-5"],
+        h1!["Forms"],
+        hr![],
+        ExampleBox::new("Typeahead")
+            .content(div![
+                Typeahead::new()
+                    .placeholder("Search languages")
+                    .view(&model.typeahead, Msg::TypeaheadMsg),
+                div![
+                    C!["pt-2", "text-muted"],
+                    "Selected: ",
+                    model.typeahead.selected().copied().unwrap_or("-"),
+                ],
+            ])
+            .code(
+                r#"pub fn init(orders: &mut impl Orders<Msg>) -> Model {
+    Model {
+        typeahead: typeahead::init(
+            typeahead::Source::closure(search_languages),
+            &mut orders.proxy(Msg::TypeaheadMsg),
+        ),
+    }
+}
+
+pub enum Msg {
+    TypeaheadMsg(typeahead::Msg<&'static str>),
+}
+
+pub fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {
+    match msg {
+        Msg::TypeaheadMsg(msg) => {
+            typeahead::update(msg, &mut model.typeahead, &mut orders.proxy(Msg::TypeaheadMsg))
+        }
+    }
+}
+
+Typeahead::new()
+    .placeholder("Search languages")
+    .view(&model.typeahead, Msg::TypeaheadMsg),"#
+            ),
+        ExampleBox::new("Tag input")
+            .content(div![TagInput::new()
+                .placeholder("Add recipients")
+                .suggestions(vec!["bob@example.com", "carol@example.com"])
+                .view(&model.tag_input, Msg::TagInputMsg),])
+            .code(
+                r#"pub fn init(orders: &mut impl Orders<Msg>) -> Model {
+    Model {
+        tag_input: tag_input::init(vec!["alice@example.com".to_owned()])
+            .validator(validate_email),
+    }
+}
+
+TagInput::new()
+    .placeholder("Add recipients")
+    .suggestions(vec!["bob@example.com", "carol@example.com"])
+    .view(&model.tag_input, Msg::TagInputMsg),"#
+            ),
+        ExampleBox::new("File upload")
+            .content(div![FileUpload::new()
+                .label("Choose images or PDFs")
+                .add_on_select(Msg::StartUploads)
+                .view(&model.file_upload, Msg::FileUploadMsg),])
+            .code(
+                r#"pub fn init(orders: &mut impl Orders<Msg>) -> Model {
+    Model {
+        file_upload: file_upload::init()
+            .accept(vec!["image/*", ".pdf"])
+            .max_size(5. * 1024. * 1024.)
+            .max_count(3),
+    }
+}
+
+pub fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {
+    match msg {
+        Msg::StartUploads(files) => {
+            for file in files {
+                model.file_upload.set_progress(&file, 0.);
+                // Send the file and report its progress with `set_progress`.
+            }
+        }
+    }
+}
+
+FileUpload::new()
+    .label("Choose images or PDFs")
+    .add_on_select(Msg::StartUploads)
+    .view(&model.file_upload, Msg::FileUploadMsg),"#
+            ),
+    ]
+}
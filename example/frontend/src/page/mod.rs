@@ -4,14 +4,23 @@ use seed::{prelude::*, *};
 use seed_bootstrap::button;
 use seed_bootstrap::button_group;
 use seed_bootstrap::dropdown;
+use seed_bootstrap::file_upload;
+use seed_bootstrap::instances::Instances;
 use seed_bootstrap::navbar;
-use std::{collections::HashMap, iter::FromIterator};
+use seed_bootstrap::offcanvas;
+use seed_bootstrap::priority_nav;
+use seed_bootstrap::scrollspy;
+use seed_bootstrap::tag_input;
+use seed_bootstrap::typeahead;
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 
+const UPLOAD_TICK_MS: u32 = 400;
+
 mod button_groups;
 mod buttons;
 mod dropdowns;
+mod forms;
 mod navbars;
 
 pub mod not_found;
@@ -23,12 +32,37 @@ pub mod not_found;
 pub fn init(page: Page, orders: &mut impl Orders<Msg>) -> Model {
     Model {
         button_toggled: false,
-        dropdowns: HashMap::from_iter(DropdownID::iter().map(|id| {
-            let mut orders = orders.proxy(move |msg| Msg::DropdownMsg(msg, id));
-            (id, dropdown::init(&mut orders))
-        })),
-        navbar_expanded: false,
+        dropdowns: Instances::init(Msg::DropdownMsg, DropdownID::iter(), orders, |_, orders| {
+            dropdown::init(orders)
+        }),
+        file_upload: file_upload::init()
+            .accept(vec!["image/*", ".pdf"])
+            .max_size(5. * 1024. * 1024.)
+            .max_count(3),
+        multi_selected: vec!["Rust"],
+        next_row: 4,
+        row_dropdowns: Instances::init(Msg::RowDropdownMsg, 1..=3, orders, |_, orders| {
+            dropdown::init(orders)
+        }),
+        navbar: navbar::init(&mut orders.proxy(Msg::NavbarMsg)),
+        drawer: offcanvas::init(&mut orders.proxy(Msg::DrawerMsg)),
+        priority_nav: priority_nav::init(&mut orders.proxy(Msg::PriorityNavMsg)),
+        scrollspy: scrollspy::init(&mut orders.proxy(Msg::ScrollspyMsg)).targets(vec![
+            "scrollspy-intro",
+            "scrollspy-usage",
+            "scrollspy-api",
+        ]),
+        sidebar: offcanvas::init(&mut orders.proxy(Msg::SidebarMsg))
+            .overlay_below(offcanvas::Breakpoint::Md),
+        tag_input: tag_input::init(vec!["alice@example.com".to_owned()])
+            .validator(forms::validate_email),
+        typeahead: typeahead::init(
+            typeahead::Source::closure(forms::search_languages),
+            &mut orders.proxy(Msg::TypeaheadMsg),
+        ),
         page,
+        url: Url::current(),
+        _url_changed_sub: orders.subscribe_with_handle(Msg::UrlChanged),
     }
 }
 
@@ -38,9 +72,22 @@ pub fn init(page: Page, orders: &mut impl Orders<Msg>) -> Model {
 
 pub struct Model {
     button_toggled: bool,
-    dropdowns: HashMap<DropdownID, dropdown::Model>,
-    navbar_expanded: bool,
+    dropdowns: Instances<DropdownID, dropdown::Model, Msg>,
+    file_upload: file_upload::Model,
+    multi_selected: Vec<&'static str>,
+    next_row: u32,
+    row_dropdowns: Instances<u32, dropdown::Model, Msg>,
+    navbar: navbar::Model,
+    drawer: offcanvas::Model,
+    priority_nav: priority_nav::Model,
+    scrollspy: scrollspy::Model,
+    sidebar: offcanvas::Model,
+    tag_input: tag_input::Model,
+    typeahead: typeahead::Model<&'static str>,
     page: Page,
+    // The current URL, the "Active links" example highlights links for it.
+    url: Url,
+    _url_changed_sub: SubHandle,
 }
 
 #[derive(Eq, PartialEq, Clone, Copy, EnumIter)]
@@ -49,6 +96,7 @@ pub enum Page {
     ButtonGroups,
     Dropdowns,
     Navbars,
+    Forms,
 }
 
 impl Default for Page {
@@ -71,7 +119,24 @@ pub enum DropdownID {
     Nested,
     NestedVertical,
     SplitButton,
+    MenuItems,
+    Submenus,
+    Searchable,
+    MultiSelect,
+    DropUp,
+    DropRight,
+    DropLeft,
+    AlignRight,
+    ResponsiveAlign,
+    InputGroup,
+    Derived,
+    PlacementBottomEnd,
+    PlacementTopStart,
     NavBarButton,
+    NavBarUser,
+    NavTabs,
+    NavHover,
+    NavMegaMenu,
 }
 
 // ------ ------
@@ -79,23 +144,99 @@ pub enum DropdownID {
 // ------ ------
 
 pub enum Msg {
+    UrlChanged(subs::UrlChanged),
     ToggleButton,
     DropdownMsg(dropdown::Msg, DropdownID),
-    ToggleNavbar,
+    RowDropdownMsg(dropdown::Msg, u32),
+    AddRow,
+    RemoveRow(u32),
+    NavbarMsg(navbar::Msg),
+    DrawerMsg(offcanvas::Msg),
+    SidebarMsg(offcanvas::Msg),
+    PriorityNavMsg(priority_nav::Msg),
+    ScrollspyMsg(scrollspy::Msg),
+    FileUploadMsg(file_upload::Msg),
+    StartUploads(Vec<web_sys::File>),
+    UploadProgressed(web_sys::File),
+    MultiSelectChanged(Vec<&'static str>),
+    TagInputMsg(tag_input::Msg),
+    TypeaheadMsg(typeahead::Msg<&'static str>),
     NoOp,
 }
 
 pub fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {
     match msg {
+        Msg::UrlChanged(subs::UrlChanged(url)) => model.url = url,
         Msg::ToggleButton => model.button_toggled = !model.button_toggled,
-        Msg::DropdownMsg(msg, id) => dropdown::update(
+        Msg::DropdownMsg(msg, id) => model.dropdowns.update(msg, id, orders),
+        Msg::RowDropdownMsg(msg, row) => model.row_dropdowns.update(msg, row, orders),
+        Msg::AddRow => {
+            model
+                .row_dropdowns
+                .add(model.next_row, orders, |_, orders| dropdown::init(orders));
+            model.next_row += 1;
+        }
+        Msg::RemoveRow(row) => {
+            model.row_dropdowns.remove(&row);
+        }
+        Msg::NavbarMsg(msg) => {
+            navbar::update(msg, &mut model.navbar, &mut orders.proxy(Msg::NavbarMsg))
+        }
+        Msg::DrawerMsg(msg) => {
+            offcanvas::update(msg, &mut model.drawer, &mut orders.proxy(Msg::DrawerMsg))
+        }
+        Msg::SidebarMsg(msg) => {
+            offcanvas::update(msg, &mut model.sidebar, &mut orders.proxy(Msg::SidebarMsg))
+        }
+        Msg::PriorityNavMsg(msg) => priority_nav::update(
+            msg,
+            &mut model.priority_nav,
+            &mut orders.proxy(Msg::PriorityNavMsg),
+        ),
+        Msg::ScrollspyMsg(msg) => scrollspy::update(
+            msg,
+            &mut model.scrollspy,
+            &mut orders.proxy(Msg::ScrollspyMsg),
+        ),
+        Msg::FileUploadMsg(msg) => file_upload::update(
             msg,
-            model.dropdowns.get_mut(&id).unwrap(),
-            &mut orders.proxy(move |msg| Msg::DropdownMsg(msg, id)),
+            &mut model.file_upload,
+            &mut orders.proxy(Msg::FileUploadMsg),
         ),
-        Msg::ToggleNavbar => {
-            model.navbar_expanded = !model.navbar_expanded;
+        // Uploads are simulated, a real app would send the files to a server.
+        Msg::StartUploads(files) => {
+            for file in files {
+                model.file_upload.set_progress(&file, 0.);
+                orders.perform_cmd(cmds::timeout(UPLOAD_TICK_MS, || {
+                    Msg::UploadProgressed(file)
+                }));
+            }
+        }
+        Msg::UploadProgressed(file) => {
+            let progress = model
+                .file_upload
+                .progress(&file)
+                .map(|progress| progress + 20.);
+            if let Some(progress) = progress {
+                model.file_upload.set_progress(&file, progress);
+                if progress < 100. {
+                    orders.perform_cmd(cmds::timeout(UPLOAD_TICK_MS, || {
+                        Msg::UploadProgressed(file)
+                    }));
+                }
+            }
         }
+        Msg::MultiSelectChanged(selected) => model.multi_selected = selected,
+        Msg::TagInputMsg(msg) => tag_input::update(
+            msg,
+            &mut model.tag_input,
+            &mut orders.proxy(Msg::TagInputMsg),
+        ),
+        Msg::TypeaheadMsg(msg) => typeahead::update(
+            msg,
+            &mut model.typeahead,
+            &mut orders.proxy(Msg::TypeaheadMsg),
+        ),
         Msg::NoOp => {
             orders.skip();
         }
@@ -119,7 +260,9 @@ pub fn view(model: &Model) -> Node<Msg> {
 
 fn navbar() -> Node<Msg> {
     navbar::NavBar::new(plain!("Seed Bootstrap"), "/components")
-        .add_attrs(C!["navbar-dark", "bg-dark", "py-2", "shadow"])
+        .dark()
+        .background(navbar::Background::Dark)
+        .add_attrs(C!["py-2", "shadow"])
         .view()
 }
 
@@ -149,6 +292,7 @@ fn sidebar_items(selected_page: Page) -> Node<Msg> {
                         Page::ButtonGroups => "Button Groups",
                         Page::Dropdowns => "Dropdowns",
                         Page::Navbars => "Navbars",
+                        Page::Forms => "Forms",
                     };
                     button::Button::new(title)
                         .add_attrs(C!["text-left"])
@@ -181,6 +325,7 @@ fn main(model: &Model) -> Node<Msg> {
             Page::ButtonGroups => button_groups::view(model),
             Page::Dropdowns => dropdowns::view(model),
             Page::Navbars => navbars::view(model),
+            Page::Forms => forms::view(model),
         }
     ]
 }
@@ -1,8 +1,15 @@
-use super::{DropdownID, ExampleBox, Model, Msg};
+use super::{DropdownID, ExampleBox, Model, Msg, Page};
+use crate::Route;
 use seed::{prelude::*, *};
-use seed_bootstrap::button::{self, Button};
-use seed_bootstrap::dropdown::{self, Dropdown};
-use seed_bootstrap::navbar::{Nav, NavBar, NavLink};
+use seed_bootstrap::button::Button;
+use seed_bootstrap::dropdown::{self, Align, Dropdown};
+use seed_bootstrap::nav;
+use seed_bootstrap::navbar::{
+    Background, Expand, Nav, NavBar, NavLink, NavText, Placement, Scheme, SearchForm, UrlMatch,
+};
+use seed_bootstrap::offcanvas::{self, Offcanvas};
+use seed_bootstrap::priority_nav::PriorityNav;
+use seed_bootstrap::scrollspy;
 
 pub fn view(model: &Model) -> Node<Msg> {
     div![
@@ -12,9 +19,10 @@ pub fn view(model: &Model) -> Node<Msg> {
         ExampleBox::new("Basic example")
             .content(div![
                 NavBar::new(plain!("Navbar"), "#")
-                    .fixed_top(false)
-                    .update_toggle(|toggle| toggle.add_on_click(|_| Msg::ToggleNavbar))
-                    .add_attrs(C!["navbar-expand-lg", "navbar-light", "bg-light"])
+                    .placement(Placement::Static)
+                    .expand(Expand::Lg)
+                    .light()
+                    .background(Background::Light)
                     .content(vec![
                         Nav::default()
                             .add_attrs(C!["mr-auto"])
@@ -29,22 +37,29 @@ pub fn view(model: &Model) -> Node<Msg> {
                                         dropdown::Item::divider(),
                                         dropdown::Item::button("Something else here", ()),
                                     ])
-                                    .view_in_nav(&model.dropdowns[&DropdownID::NavBarButton], |msg| Msg::DropdownMsg(msg, DropdownID::NavBarButton)),
+                                    .view_in_nav(&model.dropdowns[&DropdownID::NavBarButton], model.dropdowns.to_msg(&DropdownID::NavBarButton)),
                             ]
                             ).view(),
                         // --- search ---
-                        form![C!["form-inline", "my-2", "my-lg-0"],
-                            input![C!["form-control", "mr-sm-2"], attrs!{At::Type => "text", At::Placeholder => "Search", At::from("aria-label") => "Search"}],
-                            Button::new("Search").outline().success().button(button::Type::Submit).add_attrs(C!["my-2", "my-sm-0"]),
-                        ]
+                        SearchForm::new("navbar-basic-search").view(),
                     ])
-                    .view_collapsable(model.navbar_expanded, "navbarsExampleDefault")
+                    .view_with_model(&model.navbar, Msg::NavbarMsg)
             ])
             .code(
-r##"NavBar::new(plain!("Navbar"), "#")
-    .fixed_top(false)
-    .update_toggle(|toggle| toggle.add_on_click(|_| Msg::ToggleNavbar))
-    .add_attrs(C!["navbar-expand-lg", "navbar-light", "bg-light"])
+r##"// init
+navbar: navbar::init(&mut orders.proxy(Msg::NavbarMsg)),
+
+// update
+Msg::NavbarMsg(msg) => {
+    navbar::update(msg, &mut model.navbar, &mut orders.proxy(Msg::NavbarMsg))
+}
+
+// view
+NavBar::new(plain!("Navbar"), "#")
+    .placement(Placement::Static)
+    .expand(Expand::Lg)
+    .light()
+    .background(Background::Light)
     .content(vec![
         Nav::default()
             .add_attrs(C!["mr-auto"])
@@ -59,16 +74,483 @@ r##"NavBar::new(plain!("Navbar"), "#")
                         dropdown::Item::divider(),
                         dropdown::Item::button("Something else here", ()),
                     ])
-                    .view_in_nav(&model.dropdowns[&DropdownID::NavBarButton], |msg| Msg::DropdownMsg(msg, DropdownID::NavBarButton)),
+                    .view_in_nav(&model.dropdowns[&DropdownID::NavBarButton], model.dropdowns.to_msg(&DropdownID::NavBarButton)),
             ]
             ).view(),
         // --- search ---
-        form![C!["form-inline", "my-2", "my-lg-0"],
-            input![C!["form-control", "mr-sm-2"], attrs!{At::Type => "text", At::Placeholder => "Search", At::from("aria-label") => "Search"}],
-            Button::new("Search").outline().success().button(button::Type::Submit).add_attrs(C!["my-2", "my-sm-0"]),
-        ]
+        SearchForm::new("navbar-basic-search").view(),
+    ])
+    .view_with_model(&model.navbar, Msg::NavbarMsg)"##
+            ),
+        ExampleBox::new("Header")
+            .content(div![
+                NavBar::new(plain!("Navbar"), "#")
+                    .placement(Placement::Static)
+                    .expand(Expand::Always)
+                    .dark()
+                    .background(Background::Dark)
+                    .content(vec![
+                        Nav::default().content(vec![
+                            NavLink::new("Dashboard", "#").view(),
+                            NavLink::new("Reports", "#").view(),
+                        ]).view(),
+                        Nav::default().align_right().content(vec![
+                            Dropdown::new("Alice")
+                                .align(Align::Right)
+                                .items(vec![
+                                    dropdown::Item::button("Profile", ()),
+                                    dropdown::Item::button("Settings", ()),
+                                    dropdown::Item::divider(),
+                                    dropdown::Item::button("Sign out", ()),
+                                ])
+                                .view_in_nav(&model.dropdowns[&DropdownID::NavBarUser], model.dropdowns.to_msg(&DropdownID::NavBarUser)),
+                        ]).view(),
+                        SearchForm::new("navbar-header-search")
+                            .placeholder("Search reports")
+                            .on_submit(|| Msg::NoOp)
+                            .update_button(|button| button.outline().light())
+                            .view(),
+                        NavText::new(plain!("v1.2.0")).add_attrs(C!["ml-3"]).view(),
+                    ])
+                    .view()
+            ])
+            .code(
+r##"NavBar::new(plain!("Navbar"), "#")
+    .placement(Placement::Static)
+    .expand(Expand::Always)
+    .dark()
+    .background(Background::Dark)
+    .content(vec![
+        Nav::default().content(vec![
+            NavLink::new("Dashboard", "#").view(),
+            NavLink::new("Reports", "#").view(),
+        ]).view(),
+        Nav::default().align_right().content(vec![
+            Dropdown::new("Alice")
+                .align(Align::Right)
+                .items(vec![
+                    dropdown::Item::button("Profile", ()),
+                    dropdown::Item::button("Settings", ()),
+                    dropdown::Item::divider(),
+                    dropdown::Item::button("Sign out", ()),
+                ])
+                .view_in_nav(&model.dropdowns[&DropdownID::NavBarUser], model.dropdowns.to_msg(&DropdownID::NavBarUser)),
+        ]).view(),
+        SearchForm::new("navbar-header-search")
+            .placeholder("Search reports")
+            .on_submit(|| Msg::Search)
+            .update_button(|button| button.outline().light())
+            .view(),
+        NavText::new(plain!("v1.2.0")).add_attrs(C!["ml-3"]).view(),
+    ])
+    .view()"##
+            ),
+        ExampleBox::new("Navs")
+            .content(div![
+                nav::Nav::new()
+                    .tabs()
+                    .add_attrs(C!["mb-3"])
+                    .content(vec![
+                        NavLink::new("Active", "#").active(true).view(),
+                        Dropdown::new("Dropdown")
+                            .items(vec![
+                                dropdown::Item::button("Action", ()),
+                                dropdown::Item::button("Another action", ()),
+                            ])
+                            .view_in_nav(&model.dropdowns[&DropdownID::NavTabs], model.dropdowns.to_msg(&DropdownID::NavTabs)),
+                        NavLink::new("Link", "#").view(),
+                        NavLink::new("Disabled", "#").disabled(true).view(),
+                    ])
+                    .view(),
+                nav::Nav::new()
+                    .pills()
+                    .fill()
+                    .add_attrs(C!["mb-3"])
+                    .content(vec![
+                        NavLink::new("Active", "#").active(true).view(),
+                        NavLink::new("Much longer nav link", "#").view(),
+                        NavLink::new("Link", "#").view(),
+                    ])
+                    .view(),
+                div![
+                    C!["card"],
+                    div![
+                        C!["card-header"],
+                        nav::Nav::new()
+                            .tabs()
+                            .card_header()
+                            .content(vec![
+                                NavLink::new("Overview", "#").active(true).view(),
+                                NavLink::new("Details", "#").view(),
+                            ])
+                            .view(),
+                    ],
+                    div![C!["card-body"], "Tabs aligned with the card header."],
+                ],
+            ])
+            .code(
+r##"nav::Nav::new()
+    .tabs()
+    .content(vec![
+        NavLink::new("Active", "#").active(true).view(),
+        Dropdown::new("Dropdown")
+            .items(vec![
+                dropdown::Item::button("Action", ()),
+                dropdown::Item::button("Another action", ()),
+            ])
+            .view_in_nav(&model.dropdowns[&DropdownID::NavTabs], model.dropdowns.to_msg(&DropdownID::NavTabs)),
+        NavLink::new("Link", "#").view(),
+        NavLink::new("Disabled", "#").disabled(true).view(),
+    ])
+    .view(),
+nav::Nav::new()
+    .pills()
+    .fill()
+    .content(vec![...])
+    .view(),
+// inside div.card-header
+nav::Nav::new()
+    .tabs()
+    .card_header()
+    .content(vec![...])
+    .view()"##
+            ),
+        ExampleBox::new("Scrollspy")
+            .content(div![
+                C!["row"],
+                div![
+                    C!["col-4"],
+                    div![
+                        C!["sticky-top"],
+                        style! {St::Top => px(16)},
+                        scrollspy::view_list_group(
+                            &model.scrollspy,
+                            vec![("Introduction", "scrollspy-intro"), ("Usage", "scrollspy-usage"), ("API", "scrollspy-api")],
+                        ),
+                        nav::Nav::new()
+                            .pills()
+                            .vertical()
+                            .add_attrs(C!["mt-3"])
+                            .content(vec![
+                                model.scrollspy.nav_link("Introduction", "scrollspy-intro").view(),
+                                model.scrollspy.nav_link("Usage", "scrollspy-usage").view(),
+                                model.scrollspy.nav_link("API", "scrollspy-api").view(),
+                            ])
+                            .view(),
+                    ],
+                ],
+                div![
+                    C!["col-8"],
+                    [("scrollspy-intro", "Introduction"), ("scrollspy-usage", "Usage"), ("scrollspy-api", "API")]
+                        .iter()
+                        .map(|(id, title)| div![
+                            id!(id),
+                            style! {St::MinHeight => px(320)},
+                            h4![title],
+                            p!["Scroll the page to see the table of contents follow the section in view."],
+                        ]),
+                ],
+            ])
+            .code(
+r##"// init
+scrollspy: scrollspy::init(&mut orders.proxy(Msg::ScrollspyMsg))
+    .targets(vec!["scrollspy-intro", "scrollspy-usage", "scrollspy-api"]),
+
+// update
+Msg::ScrollspyMsg(msg) => {
+    scrollspy::update(msg, &mut model.scrollspy, &mut orders.proxy(Msg::ScrollspyMsg))
+}
+
+// view
+scrollspy::view_list_group(
+    &model.scrollspy,
+    vec![("Introduction", "scrollspy-intro"), ("Usage", "scrollspy-usage"), ("API", "scrollspy-api")],
+),
+nav::Nav::new()
+    .pills()
+    .vertical()
+    .content(vec![
+        model.scrollspy.nav_link("Introduction", "scrollspy-intro").view(),
+        model.scrollspy.nav_link("Usage", "scrollspy-usage").view(),
+        model.scrollspy.nav_link("API", "scrollspy-api").view(),
+    ])
+    .view(),
+
+// elsewhere, e.g. to update the page title
+orders.subscribe(|scrollspy::ActiveSectionChanged(id)| Msg::SectionChanged(id));"##
+            ),
+        ExampleBox::new("Offcanvas drawer")
+            .content(div![
+                Button::new("Open menu").add_on_click(|_| Msg::DrawerMsg(offcanvas::Msg::Open)).view(),
+                Offcanvas::new()
+                    .header(h5![C!["p-3", "mb-0", "border-bottom"], "Menu"])
+                    .items(offcanvas_items())
+                    .view(&model.drawer, Msg::DrawerMsg),
+            ])
+            .code(
+r##"Button::new("Open menu").add_on_click(|_| Msg::DrawerMsg(offcanvas::Msg::Open)).view(),
+Offcanvas::new()
+    .header(h5![C!["p-3", "mb-0", "border-bottom"], "Menu"])
+    .items(vec![
+        Item::link(NavLink::new("Dashboard", "#").icon(icon("▦"))),
+        Item::section("Reports", vec![
+            NavLink::new("Sales", "#").icon(icon("$")),
+            NavLink::new("Traffic", "#").icon(icon("↗")),
+        ])
+        .icon(icon("≡")),
+        Item::link(NavLink::new("Settings", "#").icon(icon("⚙"))),
     ])
-    .view_collapsable(model.navbar_expanded, "navbarsExampleDefault")"##
+    .view(&model.drawer, Msg::DrawerMsg)"##
+            ),
+        ExampleBox::new("Sidebar")
+            .content(div![
+                C!["d-flex", "border"],
+                style! {St::Height => px(260)},
+                Offcanvas::new()
+                    .header(h5![C!["p-3", "mb-0", "border-bottom"], "Menu"])
+                    .items(offcanvas_items())
+                    .view(&model.sidebar, Msg::SidebarMsg),
+                div![
+                    C!["p-3"],
+                    Button::new(if model.sidebar.is_overlay() { "Open menu" } else { "Toggle mini mode" })
+                        .secondary()
+                        .outline()
+                        .add_on_click(|_| Msg::SidebarMsg(offcanvas::Msg::Toggle))
+                        .view(),
+                    p![C!["mt-3", "text-muted"], "The sidebar turns into a drawer on small screens."],
+                ],
+            ])
+            .code(
+r##"// init
+sidebar: offcanvas::init(&mut orders.proxy(Msg::SidebarMsg))
+    .overlay_below(offcanvas::Breakpoint::Md),
+
+// view
+div![
+    C!["d-flex"],
+    Offcanvas::new()
+        .header(h5![C!["p-3", "mb-0", "border-bottom"], "Menu"])
+        .items(items)
+        .view(&model.sidebar, Msg::SidebarMsg),
+    Button::new(if model.sidebar.is_overlay() { "Open menu" } else { "Toggle mini mode" })
+        .add_on_click(|_| Msg::SidebarMsg(offcanvas::Msg::Toggle))
+        .view(),
+]"##
+            ),
+        ExampleBox::new("Active links")
+            .content(div![{
+                let url = &model.url;
+                NavBar::new(plain!("Navbar"), "#")
+                    .placement(Placement::Static)
+                    .expand(Expand::Always)
+                    .light()
+                    .background(Background::Light)
+                    .content(Nav::default().content(vec![
+                        NavLink::new("Components", "/components").active_for_url(url, UrlMatch::Prefix).view(),
+                        NavLink::new("Dropdowns", Route::Components(Page::Dropdowns).to_href().into_owned())
+                            .active_for_url(url, UrlMatch::Exact)
+                            .view(),
+                        NavLink::new("Navbars", Route::Components(Page::Navbars).to_href().into_owned())
+                            .active_for_url(url, UrlMatch::Exact)
+                            .view(),
+                    ]).view())
+                    .view()
+            }])
+            .code(
+r##"// init
+url: Url::current(),
+_url_changed_sub: orders.subscribe_with_handle(Msg::UrlChanged),
+
+// update
+Msg::UrlChanged(subs::UrlChanged(url)) => model.url = url,
+
+// view
+Nav::default().content(vec![
+    NavLink::new("Components", "/components").active_for_url(&model.url, UrlMatch::Prefix).view(),
+    NavLink::new("Dropdowns", "/components/dropdowns").active_for_url(&model.url, UrlMatch::Exact).view(),
+    NavLink::new("Navbars", "/components/navbars").active_for_url(&model.url, UrlMatch::Exact).view(),
+]).view()"##
+            ),
+        ExampleBox::new("Color schemes")
+            .content(div![
+                [
+                    (Scheme::Dark, Background::Dark),
+                    (Scheme::Dark, Background::Primary),
+                    (Scheme::Light, Background::Light),
+                ]
+                .iter()
+                .map(|(scheme, background)| {
+                    NavBar::new(plain!("Navbar"), "#")
+                        .placement(Placement::Static)
+                        .expand(Expand::Always)
+                        .scheme(*scheme)
+                        .background(*background)
+                        .add_attrs(C!["mb-2"])
+                        .content(Nav::default().content(vec![
+                            NavLink::new("Home", "#").active(true).view(),
+                            NavLink::new("Features", "#").view(),
+                            NavLink::new("Pricing", "#").view(),
+                        ]).view())
+                        .view()
+                })
+            ])
+            .code(
+r##"NavBar::new(plain!("Navbar"), "#")
+    .placement(Placement::Static)
+    .expand(Expand::Always)
+    .scheme(Scheme::Dark)
+    .background(Background::Primary)
+    .content(Nav::default().content(vec![
+        NavLink::new("Home", "#").active(true).view(),
+        NavLink::new("Features", "#").view(),
+        NavLink::new("Pricing", "#").view(),
+    ]).view())
+    .view()"##
             ),
+        ExampleBox::new("Hover and mega menu")
+            .content(
+                NavBar::new(plain!("Navbar"), "#")
+                    .placement(Placement::Static)
+                    .expand(Expand::Always)
+                    .light()
+                    .background(Background::Light)
+                    .content(Nav::default().content(vec![
+                        NavLink::new("Home", "#").view(),
+                        Dropdown::new("Company")
+                            .open_on_hover(true)
+                            .items(vec![
+                                dropdown::Item::a("About us", (), "#"),
+                                dropdown::Item::a("Careers", (), "#"),
+                                dropdown::Item::a("Press", (), "#"),
+                            ])
+                            .view_in_nav(&model.dropdowns[&DropdownID::NavHover], model.dropdowns.to_msg(&DropdownID::NavHover)),
+                        Dropdown::new("Products")
+                            .open_on_hover(true)
+                            .mega_menu(true)
+                            .items(mega_menu_items())
+                            .view_in_nav(&model.dropdowns[&DropdownID::NavMegaMenu], model.dropdowns.to_msg(&DropdownID::NavMegaMenu)),
+                    ]).view())
+                    .view()
+            )
+            .code(
+r##"Nav::default().content(vec![
+    Dropdown::new("Company")
+        .open_on_hover(true)
+        .items(vec![
+            dropdown::Item::a("About us", (), "#"),
+            dropdown::Item::a("Careers", (), "#"),
+        ])
+        .view_in_nav(&model.dropdowns[&DropdownID::NavHover], model.dropdowns.to_msg(&DropdownID::NavHover)),
+    Dropdown::new("Products")
+        .open_on_hover(true)
+        .mega_menu(true)
+        .items(vec![
+            // Every header starts a new column.
+            dropdown::Item::header("Analytics"),
+            dropdown::Item::a("Dashboards", (), "#"),
+            dropdown::Item::a("Reports", (), "#"),
+            dropdown::Item::header("Automation"),
+            dropdown::Item::a("Workflows", (), "#"),
+            dropdown::Item::a("Integrations", (), "#"),
+        ])
+        .view_in_nav(&model.dropdowns[&DropdownID::NavMegaMenu], model.dropdowns.to_msg(&DropdownID::NavMegaMenu)),
+]).view()"##
+            ),
+        ExampleBox::new("Priority+")
+            .content(
+                NavBar::new(plain!("Navbar"), "#")
+                    .placement(Placement::Static)
+                    .priority_plus()
+                    .dark()
+                    .background(Background::Dark)
+                    .content(
+                        PriorityNav::new(priority_links())
+                            .view(&model.priority_nav, Msg::PriorityNavMsg)
+                    )
+                    .view()
+            )
+            .code(
+r##"// init
+priority_nav: priority_nav::init(&mut orders.proxy(Msg::PriorityNavMsg)),
+
+// update
+Msg::PriorityNavMsg(msg) => {
+    priority_nav::update(msg, &mut model.priority_nav, &mut orders.proxy(Msg::PriorityNavMsg))
+}
+
+// view
+NavBar::new(plain!("Navbar"), "#")
+    .placement(Placement::Static)
+    .priority_plus()
+    .dark()
+    .background(Background::Dark)
+    .content(
+        PriorityNav::new(vec![
+            NavLink::new("Home", "#").active(true),
+            NavLink::new("Features", "#"),
+            NavLink::new("Pricing", "#"),
+            // ...
+        ])
+        .view(&model.priority_nav, Msg::PriorityNavMsg)
+    )
+    .view()"##
+            ),
+    ]
+}
+
+fn mega_menu_items() -> Vec<dropdown::Item<Msg, ()>> {
+    vec![
+        dropdown::Item::header("Analytics"),
+        dropdown::Item::a("Dashboards", (), "#"),
+        dropdown::Item::a("Reports", (), "#"),
+        dropdown::Item::a("Alerts", (), "#"),
+        dropdown::Item::header("Automation"),
+        dropdown::Item::a("Workflows", (), "#"),
+        dropdown::Item::a("Integrations", (), "#"),
+        dropdown::Item::header("Resources"),
+        dropdown::Item::a("Documentation", (), "#"),
+        dropdown::Item::a("Community", (), "#"),
+        dropdown::Item::a("Support", (), "#"),
+    ]
+}
+
+fn priority_links() -> Vec<NavLink<Msg>> {
+    [
+        "Home",
+        "Features",
+        "Pricing",
+        "Customers",
+        "Integrations",
+        "Documentation",
+        "Blog",
+        "Careers",
+        "Contact",
+    ]
+    .iter()
+    .map(|title| NavLink::new(*title, "#").active(*title == "Home"))
+    .collect()
+}
+
+fn offcanvas_items() -> Vec<offcanvas::Item<Msg>> {
+    vec![
+        offcanvas::Item::link(NavLink::new("Dashboard", "#").icon(icon("▦"))),
+        offcanvas::Item::section(
+            "Reports",
+            vec![
+                NavLink::new("Sales", "#").icon(icon("$")),
+                NavLink::new("Traffic", "#").icon(icon("↗")),
+            ],
+        )
+        .icon(icon("≡")),
+        offcanvas::Item::link(NavLink::new("Settings", "#").icon(icon("⚙"))),
+    ]
+}
+
+fn icon(symbol: &'static str) -> Node<Msg> {
+    span![
+        C!["d-inline-block", "text-center", "mr-2"],
+        style! {St::Width => em(1.5)},
+        attrs! {At::from("aria-hidden") => "true"},
+        symbol,
     ]
 }
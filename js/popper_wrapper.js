@@ -1,14 +1,39 @@
-export function create_popper(toggle_element, popup_element, on_apply_styles) {
+export function create_popper(
+  toggle_element,
+  popup_element,
+  placement,
+  strategy,
+  offset_skidding,
+  offset_distance,
+  flip,
+  prevent_overflow,
+  boundary,
+  on_apply_styles,
+) {
+  const overflow_options = {
+    boundary: boundary === "clippingParents" ? "clippingParents" : document.documentElement,
+    rootBoundary: boundary === "document" ? "document" : "viewport",
+  };
   return Popper.createPopper(toggle_element, popup_element, {
-    strategy: "fixed",
-    placement: 'bottom-start',
+    strategy,
+    placement,
     modifiers: [
       {
         name: 'offset',
         options: {
-          offset: [0, 2],
+          offset: [offset_skidding, offset_distance],
         },
       },
+      {
+        name: 'flip',
+        enabled: flip,
+        options: overflow_options,
+      },
+      {
+        name: 'preventOverflow',
+        enabled: prevent_overflow,
+        options: overflow_options,
+      },
       {
         name: 'applyStyles',
         fn({ state }) {
@@ -25,6 +50,10 @@ export function create_popper(toggle_element, popup_element, on_apply_styles) {
   });
 }
 
+export function is_popper_loaded() {
+  return typeof Popper !== "undefined" && typeof Popper.createPopper === "function";
+}
+
 export function update_popper(popper_instance) {
   popper_instance.update();
 }
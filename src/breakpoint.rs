@@ -0,0 +1,30 @@
+// ------ Breakpoint ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Breakpoint {
+    Sm,
+    Md,
+    Lg,
+    Xl,
+}
+
+impl Breakpoint {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Sm => "sm",
+            Self::Md => "md",
+            Self::Lg => "lg",
+            Self::Xl => "xl",
+        }
+    }
+
+    // Bootstrap's default grid breakpoints.
+    pub(crate) fn min_width(self) -> i32 {
+        match self {
+            Self::Sm => 576,
+            Self::Md => 768,
+            Self::Lg => 992,
+            Self::Xl => 1200,
+        }
+    }
+}
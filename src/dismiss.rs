@@ -0,0 +1,138 @@
+use seed::{prelude::*, *};
+use std::{cell::RefCell, rc::Rc};
+use wasm_bindgen::JsCast;
+use web_sys::{Event, HtmlElement, KeyboardEvent, Node};
+
+type OnDismiss = dyn Fn();
+
+thread_local! {
+    static REGISTRY: RefCell<Registry> = RefCell::new(Registry::default());
+}
+
+// ------ DismissHandle ------
+
+// Keeps the popup registered until it's dropped.
+pub(crate) struct DismissHandle {
+    id: u64,
+}
+
+impl Drop for DismissHandle {
+    fn drop(&mut self) {
+        let id = self.id;
+        let _ = REGISTRY
+            .try_with(|registry| registry.borrow_mut().popups.retain(|popup| popup.id != id));
+    }
+}
+
+// Registers an open popup made of `elements`. `on_dismiss` is called when the user clicks
+// outside all of them or presses Esc while the popup is the most recently opened one.
+pub(crate) fn on_dismiss(
+    elements: Vec<ElRef<HtmlElement>>,
+    on_dismiss: impl Fn() + 'static,
+) -> DismissHandle {
+    REGISTRY.with(|registry| {
+        let mut registry = registry.borrow_mut();
+        if registry.listeners.is_none() {
+            registry.listeners = Some(Listeners::new());
+        }
+        registry.next_id += 1;
+        let id = registry.next_id;
+        registry.popups.push(Popup {
+            id,
+            elements,
+            on_dismiss: Rc::new(on_dismiss),
+        });
+        DismissHandle { id }
+    })
+}
+
+// ------ Registry ------
+
+#[derive(Default)]
+struct Registry {
+    next_id: u64,
+    popups: Vec<Popup>,
+    listeners: Option<Listeners>,
+}
+
+struct Popup {
+    id: u64,
+    elements: Vec<ElRef<HtmlElement>>,
+    on_dismiss: Rc<OnDismiss>,
+}
+
+impl Popup {
+    fn contains(&self, target: &Node) -> bool {
+        self.elements
+            .iter()
+            .filter_map(ElRef::get)
+            .any(|element| element.contains(Some(target)))
+    }
+}
+
+// ------ Listeners ------
+
+// One pair of document listeners shared by all popups.
+struct Listeners {
+    _click: Closure<dyn FnMut(Event)>,
+    _key_down: Closure<dyn FnMut(Event)>,
+}
+
+impl Listeners {
+    fn new() -> Self {
+        let click = Closure::wrap(Box::new(on_click) as Box<dyn FnMut(Event)>);
+        let key_down = Closure::wrap(Box::new(on_key_down) as Box<dyn FnMut(Event)>);
+        let document = document();
+        let _ = document.add_event_listener_with_callback("click", click.as_ref().unchecked_ref());
+        let _ =
+            document.add_event_listener_with_callback("keydown", key_down.as_ref().unchecked_ref());
+        Self {
+            _click: click,
+            _key_down: key_down,
+        }
+    }
+}
+
+fn on_click(event: Event) {
+    let target = match event
+        .target()
+        .and_then(|target| target.dyn_into::<Node>().ok())
+    {
+        Some(target) => target,
+        None => return,
+    };
+    // Callbacks may close popups and so modify the registry - collect them first.
+    let on_dismisses = REGISTRY.with(|registry| {
+        registry
+            .borrow()
+            .popups
+            .iter()
+            .filter(|popup| !popup.contains(&target))
+            .map(|popup| Rc::clone(&popup.on_dismiss))
+            .collect::<Vec<_>>()
+    });
+    for on_dismiss in on_dismisses {
+        on_dismiss();
+    }
+}
+
+fn on_key_down(event: Event) {
+    let is_escape = matches!(
+        event.dyn_ref::<KeyboardEvent>(),
+        Some(event) if event.key() == "Escape"
+    );
+    // Popups handle Esc pressed inside them on their own and prevent the default action.
+    if !is_escape || event.default_prevented() {
+        return;
+    }
+    let on_dismiss = REGISTRY.with(|registry| {
+        registry
+            .borrow()
+            .popups
+            .last()
+            .map(|popup| Rc::clone(&popup.on_dismiss))
+    });
+    if let Some(on_dismiss) = on_dismiss {
+        on_dismiss();
+    }
+}
@@ -1,17 +1,31 @@
+pub use super::breakpoint::Breakpoint;
 use super::button::Button;
+use super::dismiss::{self, DismissHandle};
+pub use super::popper::{Boundary, Placement, PopperOptions, Strategy};
+use super::popper::{PopperData, PopperError};
+pub use super::url_match::UrlMatch;
 use seed::{prelude::*, *};
+#[cfg(feature = "derive")]
+pub use seed_bootstrap_derive::DropdownItems;
 use std::{borrow::Cow, rc::Rc};
 use uuid::Uuid;
 use wasm_bindgen::JsCast;
-use web_sys::{EventTarget, HtmlElement, MouseEvent};
+use web_sys::{
+    EventTarget, HtmlElement, HtmlInputElement, KeyboardEvent, MouseEvent, PointerEvent,
+};
 
-type PopperInstance = JsValue;
+type SearchMatcher = dyn Fn(&str, &str) -> bool;
+type SelectionSummary = dyn Fn(&[&str]) -> String;
+
+const FOCUSABLE_ITEMS_SELECTOR: &str = ".dropdown-item:not(.disabled):not(:disabled)";
+const SELECTION_ACTION_ATTR: &str = "data-selection-action";
+const DEFAULT_HOVER_DELAYS_MS: (u32, u32) = (150, 300);
 
 // ------ ------
 //     Init
 // ------ ------
 
-pub fn init(orders: &mut impl Orders<Msg>) -> Model {
+pub fn init(_: &mut impl Orders<Msg>) -> Model {
     Model {
         expanded: false,
         toggle: ElRef::default(),
@@ -19,10 +33,13 @@ pub fn init(orders: &mut impl Orders<Msg>) -> Model {
         popup_style: String::new(),
         id: Uuid::new_v4().to_string(),
         popper_data: None,
-        _window_click_stream: orders
-            .stream_with_handle(streams::window_event(Ev::Click, |event| {
-                Msg::Collapse(Some(event.target().expect("event target")))
-            })),
+        popper_options: PopperOptions::default(),
+        submenus: Vec::new(),
+        search: String::new(),
+        search_input: ElRef::default(),
+        dismiss_handle: None,
+        hover_handle: None,
+        opened_on_hover: false,
     }
 }
 
@@ -37,19 +54,46 @@ pub struct Model {
     popup_style: String,
     id: String,
     popper_data: Option<PopperData>,
-    _window_click_stream: StreamHandle,
+    popper_options: PopperOptions,
+    submenus: Vec<Submenu>,
+    search: String,
+    search_input: ElRef<HtmlInputElement>,
+    dismiss_handle: Option<DismissHandle>,
+    // A pending hover open or close.
+    hover_handle: Option<CmdHandle>,
+    opened_on_hover: bool,
+}
+
+impl Model {
+    fn is_submenu_open(&self, path: &[usize]) -> bool {
+        path.len() <= self.submenus.len()
+            && self
+                .submenus
+                .iter()
+                .zip(path)
+                .all(|(submenu, index)| submenu.index == *index)
+    }
 }
 
-// ------ PopperData ------
+// ------ Submenu ------
 
-struct PopperData {
-    popper_instance: PopperInstance,
-    _on_apply_styles: Closure<dyn FnMut(String)>,
+struct Submenu {
+    index: usize,
+    trigger: ElRef<HtmlElement>,
+    popup: ElRef<HtmlElement>,
+    popup_style: String,
+    popper_data: Option<PopperData>,
 }
 
-impl Drop for PopperData {
-    fn drop(&mut self) {
-        destroy_popper(&self.popper_instance)
+impl Submenu {
+    fn new(index: usize) -> Self {
+        Self {
+            index,
+            trigger: ElRef::default(),
+            popup: ElRef::default(),
+            popup_style: String::new(),
+            popper_data: None,
+        }
     }
 }
 
@@ -58,91 +102,411 @@ impl Drop for PopperData {
 // ------ ------
 #[derive(Debug)]
 pub enum Msg {
-    ToggleClicked,
+    ToggleClicked(PopperOptions),
+    OpenAndFocus(ItemFocus, PopperOptions),
+    FocusItem(ItemFocus),
     UpdatePopper,
     Collapse(Option<EventTarget>),
+    CollapseAndFocusToggle,
     OnApplyStyles(String),
+    OpenSubmenu(Vec<usize>),
+    OpenSubmenuAndFocus(Vec<usize>),
+    CloseSubmenu(usize),
+    CloseSubmenuAndFocusTrigger(usize),
+    ShowSubmenuPopper(usize),
+    FocusSubmenu(usize),
+    OnApplySubmenuStyles(usize, String),
+    SearchChanged(String),
+    FocusSearch,
+    ActivateFirstItem,
+    // The pointer entered the toggle or the menu of a dropdown that opens on hover.
+    PointerEntered(u32, PopperOptions),
+    PointerLeft(u32),
+    OpenOnHover(PopperOptions),
+    CollapseOnHover,
+}
+
+// ------ ItemFocus ------
+
+#[derive(Debug, Clone)]
+pub enum ItemFocus {
+    Next,
+    Previous,
+    First,
+    Last,
+    StartingWith(String),
 }
 
 pub fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {
     match msg {
-        Msg::ToggleClicked => {
-            model.expanded = !model.expanded;
+        Msg::ToggleClicked(popper_options) => {
+            if model.expanded && model.opened_on_hover {
+                // Clicking a menu opened on hover keeps it open when the pointer leaves.
+                model.opened_on_hover = false;
+                model.hover_handle = None;
+            } else if model.expanded {
+                collapse(model);
+            } else {
+                expand(model, popper_options, orders);
+                orders.after_next_render(|_| Msg::FocusSearch);
+            }
+        }
+        Msg::OpenAndFocus(item_focus, popper_options) => {
+            expand(model, popper_options, orders);
+            orders.after_next_render(|_| Msg::FocusItem(item_focus));
+        }
+        Msg::FocusItem(item_focus) => {
             if model.expanded {
-                if model.popper_data.is_none() {
-                    model.popper_data = Some(show_popper(&model.toggle, &model.popup, orders));
-                }
-                orders.after_next_render(|_| Msg::UpdatePopper);
+                focus_item(&model.popup, &item_focus);
             }
         }
         Msg::UpdatePopper => {
-            if let Some(popper_data) = &model.popper_data {
-                update_popper(&popper_data.popper_instance);
+            if model.expanded && !model.popper_options.static_display {
+                PopperData::update_or_show(
+                    &mut model.popper_data,
+                    &model.toggle,
+                    &model.popup,
+                    model.popper_options.clone(),
+                    orders,
+                    Msg::OnApplyStyles,
+                );
+            }
+            for popper_data in model.submenus.iter().filter_map(|s| s.popper_data.as_ref()) {
+                popper_data.update();
             }
         }
         Msg::Collapse(event_target) => {
-            if model.expanded {
-                match event_target {
-                    Some(event_target) => {
-                        let target = event_target
-                            .dyn_into::<web_sys::Node>()
-                            .expect("event_target into node");
-                        let is_target_in_toggle = model
-                            .toggle
-                            .get()
-                            .expect("get dropdown toggle")
-                            .contains(Some(&target));
-                        let is_target_in_popup = model
-                            .popup
-                            .get()
-                            .expect("get dropdopwn popup")
-                            .contains(Some(&target));
-                        if !is_target_in_toggle && !is_target_in_popup {
-                            model.expanded = false;
-                        }
-                    }
-                    None => {
-                        model.expanded = false;
-                    }
+            if let Some(target) =
+                event_target.and_then(|target| target.dyn_into::<web_sys::Node>().ok())
+            {
+                // Submenus are nested in the popup, so they don't need to be checked separately.
+                let is_target_inside = model
+                    .toggle
+                    .get()
+                    .into_iter()
+                    .chain(model.popup.get())
+                    .any(|element| element.contains(Some(&target)));
+                if is_target_inside {
+                    return;
                 }
             }
+            collapse(model);
+        }
+        Msg::CollapseAndFocusToggle => {
+            collapse(model);
+            if let Some(toggle) = model.toggle.get() {
+                let _ = toggle.focus();
+            }
         }
         Msg::OnApplyStyles(popup_style) => model.popup_style = popup_style,
+        Msg::OpenSubmenu(path) => open_submenu(model, &path, orders),
+        Msg::OpenSubmenuAndFocus(path) => {
+            let depth = path.len() - 1;
+            open_submenu(model, &path, orders);
+            orders.after_next_render(move |_| Msg::FocusSubmenu(depth));
+        }
+        Msg::CloseSubmenu(depth) => model.submenus.truncate(depth),
+        Msg::CloseSubmenuAndFocusTrigger(depth) => {
+            if let Some(trigger) = model.submenus.get(depth).and_then(|s| s.trigger.get()) {
+                let _ = trigger.focus();
+            }
+            model.submenus.truncate(depth);
+        }
+        Msg::ShowSubmenuPopper(depth) => {
+            if let Some(submenu) = model.submenus.get_mut(depth) {
+                if submenu.popper_data.is_none() {
+                    let (app, msg_mapper) = (orders.clone_app(), orders.msg_mapper());
+                    // Submenus are shown after they are rendered, so their refs have to be mounted.
+                    submenu.popper_data = PopperData::new(
+                        &submenu.trigger,
+                        &submenu.popup,
+                        PopperOptions {
+                            placement: Placement::RightStart,
+                            strategy: Strategy::Absolute,
+                            offset: (-8., 0.),
+                            ..PopperOptions::default()
+                        },
+                        move |popup_style| {
+                            app.update(msg_mapper(Msg::OnApplySubmenuStyles(depth, popup_style)))
+                        },
+                    )
+                    .map_err(PopperError::log)
+                    .ok();
+                }
+            }
+        }
+        Msg::FocusSubmenu(depth) => {
+            if let Some(popup) = model.submenus.get(depth).and_then(|s| s.popup.get()) {
+                if let Some(item) = menu_items(&popup).first() {
+                    let _ = item.focus();
+                }
+            }
+        }
+        Msg::OnApplySubmenuStyles(depth, popup_style) => {
+            if let Some(submenu) = model.submenus.get_mut(depth) {
+                submenu.popup_style = popup_style;
+            }
+        }
+        Msg::SearchChanged(search) => {
+            model.search = search;
+            // Filtering shifts item indices, so open submenu paths are no longer valid.
+            model.submenus.clear();
+            orders.after_next_render(|_| Msg::UpdatePopper);
+        }
+        Msg::FocusSearch => {
+            if let Some(search_input) = model.search_input.get() {
+                let _ = search_input.focus();
+            }
+        }
+        Msg::ActivateFirstItem => {
+            if let Some(popup) = model.popup.get() {
+                // Skips "Select all" and "Clear" to activate the first matching item.
+                let item = menu_items(&popup)
+                    .into_iter()
+                    .find(|item| !item.has_attribute(SELECTION_ACTION_ATTR));
+                if let Some(item) = item {
+                    item.click();
+                }
+            }
+        }
+        Msg::PointerEntered(open_delay_ms, popper_options) => {
+            // Cancels a pending close.
+            model.hover_handle = None;
+            if !model.expanded {
+                model.hover_handle = Some(
+                    orders.perform_cmd_with_handle(cmds::timeout(open_delay_ms, move || {
+                        Msg::OpenOnHover(popper_options)
+                    })),
+                );
+            }
+            orders.skip();
+        }
+        Msg::PointerLeft(close_delay_ms) => {
+            // Cancels a pending open.
+            model.hover_handle = None;
+            if model.expanded && model.opened_on_hover {
+                model.hover_handle = Some(
+                    orders.perform_cmd_with_handle(cmds::timeout(close_delay_ms, || {
+                        Msg::CollapseOnHover
+                    })),
+                );
+            }
+            orders.skip();
+        }
+        Msg::OpenOnHover(popper_options) => {
+            model.hover_handle = None;
+            if model.expanded {
+                orders.skip();
+                return;
+            }
+            expand(model, popper_options, orders);
+            model.opened_on_hover = true;
+        }
+        Msg::CollapseOnHover => {
+            model.hover_handle = None;
+            if model.opened_on_hover {
+                collapse(model);
+            }
+        }
     }
 }
 
-fn show_popper(
-    toggle: &ElRef<HtmlElement>,
-    popup: &ElRef<HtmlElement>,
-    orders: &mut impl Orders<Msg>,
-) -> PopperData {
-    let (app, msg_mapper) = (orders.clone_app(), orders.msg_mapper());
+fn collapse(model: &mut Model) {
+    model.expanded = false;
+    model.dismiss_handle = None;
+    model.hover_handle = None;
+    model.opened_on_hover = false;
+    model.submenus.clear();
+    model.search.clear();
+}
+
+fn open_submenu(model: &mut Model, path: &[usize], orders: &mut impl Orders<Msg>) {
+    if !model.expanded || path.is_empty() || model.is_submenu_open(path) {
+        return;
+    }
+    let common_depth = model
+        .submenus
+        .iter()
+        .zip(path)
+        .take_while(|(submenu, index)| submenu.index == **index)
+        .count();
+    model.submenus.truncate(common_depth);
+    for (depth, index) in path.iter().enumerate().skip(common_depth) {
+        model.submenus.push(Submenu::new(*index));
+        orders.after_next_render(move |_| Msg::ShowSubmenuPopper(depth));
+    }
+}
 
-    let closure =
-        Closure::new(move |popup_style| app.update(msg_mapper(Msg::OnApplyStyles(popup_style))));
-    let closure_as_js_value = closure.as_ref().clone();
+fn expand(model: &mut Model, popper_options: PopperOptions, orders: &mut impl Orders<Msg>) {
+    model.expanded = true;
+    if model.dismiss_handle.is_none() {
+        let (app, msg_mapper) = (orders.clone_app(), orders.msg_mapper());
+        model.dismiss_handle = Some(dismiss::on_dismiss(
+            vec![model.toggle.clone(), model.popup.clone()],
+            move || app.update(msg_mapper(Msg::Collapse(None))),
+        ));
+    }
+    let is_popper_outdated = match &model.popper_data {
+        Some(popper_data) => popper_data.options() != &popper_options,
+        None => true,
+    };
+    if is_popper_outdated {
+        // The old instance has to be destroyed before the new one takes over the popup.
+        model.popper_data = None;
+        model.popper_options = popper_options;
+        if model.popper_options.static_display {
+            model.popup_style.clear();
+        } else {
+            // Reported by `Msg::UpdatePopper` if the refs still aren't mounted after the render.
+            let _ = PopperData::show(
+                &mut model.popper_data,
+                &model.toggle,
+                &model.popup,
+                model.popper_options.clone(),
+                orders,
+                Msg::OnApplyStyles,
+            );
+        }
+    }
+    orders.after_next_render(|_| Msg::UpdatePopper);
+}
 
-    let popper_instance = create_popper(
-        toggle.get().expect("get toggle"),
-        popup.get().expect("get popup"),
-        closure_as_js_value,
-    );
-    PopperData {
-        popper_instance,
-        _on_apply_styles: closure,
+fn focus_item(popup: &ElRef<HtmlElement>, item_focus: &ItemFocus) {
+    let popup = match popup.get() {
+        Some(popup) => popup,
+        None => return,
+    };
+    let active_element = document().active_element();
+    // Navigate within the (sub)menu that contains the focused item.
+    let menu = active_element
+        .as_ref()
+        .filter(|active_element| popup.contains(Some(active_element)))
+        .and_then(|active_element| active_element.closest(".dropdown-menu").ok().flatten())
+        .unwrap_or_else(|| popup.into());
+    let items = menu_items(&menu);
+    if items.is_empty() {
+        return;
     }
+    let last = items.len() - 1;
+    let current = items
+        .iter()
+        .position(|item| item.is_same_node(active_element.as_ref().map(AsRef::as_ref)));
+
+    let index = match item_focus {
+        ItemFocus::Next => current.map_or(0, |index| (index + 1).min(last)),
+        ItemFocus::Previous => current.map_or(last, |index| index.saturating_sub(1)),
+        ItemFocus::First => 0,
+        ItemFocus::Last => last,
+        ItemFocus::StartingWith(prefix) => {
+            let start = current.map_or(0, |index| index + 1);
+            let matching = (0..items.len())
+                .map(|offset| (start + offset) % items.len())
+                .find(|index| {
+                    items[*index]
+                        .text_content()
+                        .unwrap_or_default()
+                        .trim()
+                        .to_lowercase()
+                        .starts_with(prefix.as_str())
+                });
+            match matching {
+                Some(index) => index,
+                None => return,
+            }
+        }
+    };
+    let _ = items[index].focus();
+    keep_in_view(&items[index]);
 }
 
-#[wasm_bindgen(module = "/js/popper_wrapper.js")]
-extern "C" {
-    fn create_popper(
-        toggle_element: HtmlElement,
-        popup_element: HtmlElement,
-        _on_apply_styles: JsValue,
-    ) -> PopperInstance;
+fn keep_in_view(item: &HtmlElement) {
+    let container = match item.offset_parent() {
+        Some(container) => container,
+        None => return,
+    };
+    let top = item.offset_top();
+    let bottom = top + item.offset_height();
+    if top < container.scroll_top() {
+        container.set_scroll_top(top);
+    } else if bottom > container.scroll_top() + container.client_height() {
+        container.set_scroll_top(bottom - container.client_height());
+    }
+}
 
-    fn update_popper(popper_instance: &PopperInstance);
-    fn destroy_popper(popper_instance: &PopperInstance);
+fn menu_items(menu: &web_sys::Element) -> Vec<HtmlElement> {
+    let node_list = match menu.query_selector_all(FOCUSABLE_ITEMS_SELECTOR) {
+        Ok(node_list) => node_list,
+        Err(_) => return Vec::new(),
+    };
+    (0..node_list.length())
+        .filter_map(|index| node_list.get(index))
+        .filter_map(|node| node.dyn_into::<HtmlElement>().ok())
+        .filter(|item| {
+            let item_menu = item.closest(".dropdown-menu").ok().flatten();
+            menu.is_same_node(item_menu.as_ref().map(AsRef::as_ref))
+        })
+        .collect()
+}
+
+fn on_hover<Ms: 'static>(
+    (open_delay_ms, close_delay_ms): (u32, u32),
+    popper_options: PopperOptions,
+    to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
+) -> Vec<EventHandler<Ms>> {
+    // Taps fire pointer events too, touch devices open the menu with the click instead.
+    let is_touch = |event: &PointerEvent| event.pointer_type() == "touch";
+    vec![
+        pointer_ev(Ev::PointerEnter, {
+            let to_msg = to_msg.clone();
+            move |event| IF!(!is_touch(&event) => to_msg(Msg::PointerEntered(open_delay_ms, popper_options)))
+        }),
+        pointer_ev(
+            Ev::PointerLeave,
+            move |event| IF!(!is_touch(&event) => to_msg(Msg::PointerLeft(close_delay_ms))),
+        ),
+    ]
+}
+
+fn on_key_down<Ms: 'static>(
+    expanded: bool,
+    popper_options: PopperOptions,
+    to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
+) -> EventHandler<Ms> {
+    keyboard_ev(Ev::KeyDown, move |event| {
+        let msg = keyboard_msg(&event, expanded, popper_options)?;
+        Some(to_msg(msg))
+    })
+}
+
+fn keyboard_msg(
+    event: &KeyboardEvent,
+    expanded: bool,
+    popper_options: PopperOptions,
+) -> Option<Msg> {
+    let key = event.key();
+    let msg = match (key.as_str(), expanded) {
+        ("ArrowDown", false) => Msg::OpenAndFocus(ItemFocus::First, popper_options),
+        ("ArrowUp", false) => Msg::OpenAndFocus(ItemFocus::Last, popper_options),
+        ("ArrowDown", true) => Msg::FocusItem(ItemFocus::Next),
+        ("ArrowUp", true) => Msg::FocusItem(ItemFocus::Previous),
+        ("Home", true) => Msg::FocusItem(ItemFocus::First),
+        ("End", true) => Msg::FocusItem(ItemFocus::Last),
+        ("Escape", true) => Msg::CollapseAndFocusToggle,
+        // Let the browser move the focus, just close the menu.
+        ("Tab", true) => return Some(Msg::Collapse(None)),
+        (_, true)
+            if key.chars().count() == 1
+                && key != " "
+                && !event.ctrl_key()
+                && !event.meta_key()
+                && !event.alt_key() =>
+        {
+            Msg::FocusItem(ItemFocus::StartingWith(key.to_lowercase()))
+        }
+        _ => return None,
+    };
+    event.prevent_default();
+    Some(msg)
 }
 
 // ------ ------
@@ -153,9 +517,20 @@ extern "C" {
 
 pub struct Dropdown<Ms: 'static, ItemValue> {
     id: Option<Cow<'static, str>>,
-    items: Vec<Item<ItemValue>>,
+    items: Vec<Item<Ms, ItemValue>>,
     on_item_clicks: Vec<Rc<dyn Fn(MouseEvent, ItemValue) -> Ms>>,
     toggle: Button<Ms>,
+    popper_options: PopperOptions,
+    direction: Direction,
+    align: Align,
+    responsive_aligns: Vec<(Breakpoint, Align)>,
+    search: Option<Search>,
+    selection: Option<Selection<ItemValue>>,
+    on_selection_changes: Vec<Rc<dyn Fn(Vec<ItemValue>) -> Ms>>,
+    selection_labels: SelectionLabels,
+    open_on_hover: bool,
+    hover_delays_ms: (u32, u32),
+    mega_menu: bool,
 }
 
 impl<Ms: 'static, ItemValue: Clone + 'static> Dropdown<Ms, ItemValue> {
@@ -173,7 +548,7 @@ impl<Ms: 'static, ItemValue: Clone + 'static> Dropdown<Ms, ItemValue> {
         self
     }
 
-    pub fn items(mut self, items: Vec<Item<ItemValue>>) -> Self {
+    pub fn items(mut self, items: Vec<Item<Ms, ItemValue>>) -> Self {
         self.items = items;
         self
     }
@@ -193,44 +568,148 @@ impl<Ms: 'static, ItemValue: Clone + 'static> Dropdown<Ms, ItemValue> {
         self
     }
 
-    pub fn view(self, model: &Model, to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static) -> Node<Ms> {
-        let to_msg = move |msg| to_msg.clone()(msg);
-        let id = self.id.unwrap_or_else(|| model.id.clone().into());
-        let on_item_clicks = self.on_item_clicks.clone();
-
-        let toggle = self
-            .toggle
-            .add_attrs(id!(id.clone()))
-            .add_attrs(C!["dropdown-toggle"])
-            .add_attrs(attrs! {
-                At::from("aria-haspopup") => "true",
-                At::from("aria-expanded") => model.expanded,
-            })
-            .add_on_click({
-                let to_msg = to_msg.clone();
-                move |event| {
-                    event.prevent_default();
-                    to_msg(Msg::ToggleClicked)
-                }
-            })
-            .el_ref(&model.toggle)
-            .view_toggle(model.expanded);
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self.popper_options.placement = direction.placement(self.align);
+        self
+    }
 
-        div![
-            C!["dropdown"],
-            toggle,
-            div![
-                el_ref(&model.popup),
-                C!["dropdown-menu", IF!(model.expanded => "show")],
-                attrs! {
-                    At::Style => model.popup_style,
-                    At::from("aria-labelledby") => id
-                },
-                self.items
-                    .into_iter()
-                    .map(move |item| { item.into_element(to_msg.clone(), on_item_clicks.clone()) })
-            ],
-        ]
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = align;
+        self.popper_options.placement = self.direction.placement(align);
+        self
+    }
+
+    // Bootstrap's responsive alignment works only with CSS positioning, so Popper is turned off.
+    pub fn responsive_align(mut self, breakpoint: Breakpoint, align: Align) -> Self {
+        self.responsive_aligns.push((breakpoint, align));
+        self.popper_options.static_display = true;
+        self
+    }
+
+    pub fn static_display(mut self, static_display: bool) -> Self {
+        self.popper_options.static_display = static_display;
+        self
+    }
+
+    // Mouse and pen pointers open the menu after a short delay and close it again when they leave,
+    // touch devices keep opening it on click.
+    pub fn open_on_hover(mut self, open_on_hover: bool) -> Self {
+        self.open_on_hover = open_on_hover;
+        self
+    }
+
+    // How long the pointer has to stay over the dropdown before it opens and stay away before it closes.
+    pub fn hover_delays(mut self, open_delay_ms: u32, close_delay_ms: u32) -> Self {
+        self.hover_delays_ms = (open_delay_ms, close_delay_ms);
+        self
+    }
+
+    // The menu spans its nearest positioned ancestor, e.g. the navbar, and every header starts a new column.
+    // It's positioned by CSS, so Popper is turned off.
+    pub fn mega_menu(mut self, mega_menu: bool) -> Self {
+        self.mega_menu = mega_menu;
+        if mega_menu {
+            self.popper_options.static_display = true;
+        }
+        self
+    }
+
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.popper_options.placement = placement;
+        self
+    }
+
+    pub fn strategy(mut self, strategy: Strategy) -> Self {
+        self.popper_options.strategy = strategy;
+        self
+    }
+
+    pub fn offset(mut self, skidding: f64, distance: f64) -> Self {
+        self.popper_options.offset = (skidding, distance);
+        self
+    }
+
+    pub fn flip(mut self, flip: bool) -> Self {
+        self.popper_options.flip = flip;
+        self
+    }
+
+    pub fn prevent_overflow(mut self, prevent_overflow: bool) -> Self {
+        self.popper_options.prevent_overflow = prevent_overflow;
+        self
+    }
+
+    pub fn boundary(mut self, boundary: Boundary) -> Self {
+        self.popper_options.boundary = boundary;
+        self
+    }
+
+    pub fn searchable(mut self, searchable: bool) -> Self {
+        self.search = if searchable {
+            Some(self.search.unwrap_or_default())
+        } else {
+            None
+        };
+        self
+    }
+
+    pub fn search_placeholder(mut self, placeholder: impl Into<Cow<'static, str>>) -> Self {
+        self.search.get_or_insert_with(Search::default).placeholder = placeholder.into();
+        self
+    }
+
+    pub fn search_matcher(mut self, matcher: impl Fn(&str, &str) -> bool + 'static) -> Self {
+        self.search.get_or_insert_with(Search::default).matcher = Rc::new(matcher);
+        self
+    }
+
+    pub fn no_results_text(mut self, no_results_text: impl Into<Cow<'static, str>>) -> Self {
+        self.search
+            .get_or_insert_with(Search::default)
+            .no_results_text = no_results_text.into();
+        self
+    }
+
+    pub fn multi_select(mut self, selected: Vec<ItemValue>) -> Self
+    where
+        ItemValue: PartialEq,
+    {
+        self.selection = Some(Selection {
+            selected,
+            eq: ItemValue::eq,
+        });
+        self
+    }
+
+    pub fn add_on_selection_change(
+        mut self,
+        on_selection_change: impl FnOnce(Vec<ItemValue>) -> Ms + Clone + 'static,
+    ) -> Self {
+        self.on_selection_changes.push(Rc::new(move |selected| {
+            on_selection_change.clone()(selected)
+        }));
+        self
+    }
+
+    pub fn selection_summary(mut self, summary: impl Fn(&[&str]) -> String + 'static) -> Self {
+        self.selection_labels.summary = Rc::new(summary);
+        self
+    }
+
+    pub fn select_all_text(mut self, select_all_text: impl Into<Cow<'static, str>>) -> Self {
+        self.selection_labels.select_all = select_all_text.into();
+        self
+    }
+
+    pub fn clear_selection_text(mut self, clear_text: impl Into<Cow<'static, str>>) -> Self {
+        self.selection_labels.clear = clear_text.into();
+        self
+    }
+
+    pub fn view(self, model: &Model, to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static) -> Node<Ms> {
+        let mut nodes = self.view_in(Context::Standalone, model, to_msg);
+        nodes.pop().unwrap_or_else(|| empty![])
     }
 
     pub fn view_in_nav(
@@ -238,44 +717,8 @@ impl<Ms: 'static, ItemValue: Clone + 'static> Dropdown<Ms, ItemValue> {
         model: &Model,
         to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
     ) -> Node<Ms> {
-        let to_msg = move |msg| to_msg.clone()(msg);
-        let id = self.id.unwrap_or_else(|| model.id.clone().into());
-        let on_item_clicks = self.on_item_clicks.clone();
-
-        let toggle = self
-            .toggle
-            .add_attrs(id!(id.clone()))
-            .add_attrs(C!["nav-link", "dropdown-toggle"])
-            .add_attrs(attrs! {
-                At::from("aria-haspopup") => "true",
-                At::from("aria-expanded") => model.expanded,
-            })
-            .add_on_click({
-                let to_msg = to_msg.clone();
-                move |event| {
-                    event.prevent_default();
-                    to_msg(Msg::ToggleClicked)
-                }
-            })
-            .el_ref(&model.toggle)
-            .link()
-            .view_toggle(model.expanded);
-
-        li![
-            C!["nav-item", "dropdown"],
-            toggle,
-            div![
-                el_ref(&model.popup),
-                C!["dropdown-menu", IF!(model.expanded => "show")],
-                attrs! {
-                    At::Style => model.popup_style,
-                    At::from("aria-labelledby") => id,
-                },
-                self.items
-                    .into_iter()
-                    .map(move |item| { item.into_element(to_msg.clone(), on_item_clicks.clone()) })
-            ],
-        ]
+        let mut nodes = self.view_in(Context::Nav, model, to_msg);
+        nodes.pop().unwrap_or_else(|| empty![])
     }
 
     pub fn view_in_split_button(
@@ -283,45 +726,121 @@ impl<Ms: 'static, ItemValue: Clone + 'static> Dropdown<Ms, ItemValue> {
         model: &Model,
         to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
         scren_reader_title: &str,
+    ) -> Vec<Node<Ms>> {
+        let context = Context::SplitButton(scren_reader_title.to_owned().into());
+        self.view_in(context, model, to_msg)
+    }
+
+    // Split buttons render the toggle and the menu without a wrapper,
+    // all other contexts render a single node.
+    pub fn view_in(
+        self,
+        context: Context,
+        model: &Model,
+        to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
     ) -> Vec<Node<Ms>> {
         let to_msg = move |msg| to_msg.clone()(msg);
         let id = self.id.unwrap_or_else(|| model.id.clone().into());
-        let on_item_clicks = self.on_item_clicks.clone();
-
-        let toggle = self
-            .toggle
-            .add_attrs(id!(id.clone()))
-            .add_attrs(C!["dropdown-toggle"])
-            .add_attrs(attrs! {
-                At::from("aria-haspopup") => "true",
-                At::from("aria-expanded") => model.expanded,
-            })
-            .add_attrs(C!["dropdown-toggle-split"])
-            .add_on_click({
-                let to_msg = to_msg.clone();
-                move |event| {
-                    event.prevent_default();
-                    to_msg(Msg::ToggleClicked)
-                }
-            })
-            .content(span![C!["sr-only"], scren_reader_title])
-            .el_ref(&model.toggle)
-            .view_toggle(model.expanded);
-
-        vec![
-            toggle,
-            div![
-                el_ref(&model.popup),
-                C!["dropdown-menu", IF!(model.expanded => "show")],
-                attrs! {
-                    At::Style => model.popup_style,
-                    At::from("aria-labelledby") => id
+        let container_class = self.direction.container_class();
+
+        let toggle = summarize_selection(
+            self.toggle,
+            &self.items,
+            self.selection.as_ref(),
+            &self.selection_labels,
+        )
+        .add_attrs(id!(id.clone()))
+        .add_attrs(C!["dropdown-toggle"])
+        .add_attrs(attrs! {
+            At::from("aria-haspopup") => "true",
+            At::from("aria-expanded") => model.expanded,
+        })
+        .add_on_click({
+            let to_msg = to_msg.clone();
+            let popper_options = self.popper_options.clone();
+            move |event| {
+                event.prevent_default();
+                to_msg(Msg::ToggleClicked(popper_options))
+            }
+        })
+        .el_ref(&model.toggle);
+        let toggle = match &context {
+            Context::Nav => toggle.add_attrs(C!["nav-link"]).link(),
+            Context::SplitButton(screen_reader_title) => toggle
+                .add_attrs(C!["dropdown-toggle-split"])
+                .content(span![C!["sr-only"], screen_reader_title]),
+            _ => toggle,
+        };
+        let mut toggle = toggle.view_toggle(model.expanded);
+        toggle.add_event_handler(on_key_down(
+            model.expanded,
+            self.popper_options.clone(),
+            to_msg.clone(),
+        ));
+        if self.open_on_hover {
+            for handler in on_hover(
+                self.hover_delays_ms,
+                self.popper_options.clone(),
+                to_msg.clone(),
+            ) {
+                toggle.add_event_handler(handler);
+            }
+        }
+        // The menu is hovered after the pointer leaves the toggle, so it has to keep the dropdown open.
+        let menu_hover_handlers = IF!(self.open_on_hover => on_hover(
+            self.hover_delays_ms,
+            self.popper_options.clone(),
+            to_msg.clone(),
+        ));
+        let mega_menu_class = IF!(self.mega_menu => "position-static");
+
+        let menu = div![
+            el_ref(&model.popup),
+            C!["dropdown-menu", IF!(model.expanded => "show")],
+            C![menu_align_classes(self.align, &self.responsive_aligns)],
+            C![IF!(self.mega_menu => "w-100")],
+            attrs! {
+                At::Style => model.popup_style,
+                At::from("aria-labelledby") => id
+            },
+            on_key_down(model.expanded, self.popper_options.clone(), to_msg.clone()),
+            menu_hover_handlers,
+            view_items(
+                self.items,
+                self.search,
+                self.mega_menu,
+                &self.selection_labels,
+                &ItemContext {
+                    model,
+                    to_msg: Rc::new(to_msg),
+                    on_item_clicks: &self.on_item_clicks,
+                    selection: self.selection.as_ref(),
+                    on_selection_changes: &self.on_selection_changes,
                 },
-                self.items
-                    .into_iter()
-                    .map(move |item| { item.into_element(to_msg.clone(), on_item_clicks.clone()) })
-            ],
-        ]
+            ),
+        ];
+
+        match context {
+            Context::Standalone => vec![div![C![container_class, mega_menu_class], toggle, menu]],
+            Context::Nav => vec![li![
+                C!["nav-item", container_class, mega_menu_class],
+                toggle,
+                menu
+            ]],
+            Context::ButtonGroup => vec![div![
+                C![
+                    "btn-group",
+                    IF!(self.direction != Direction::Down => container_class),
+                    mega_menu_class
+                ],
+                attrs! {At::from("role") => "group"},
+                toggle,
+                menu,
+            ]],
+            Context::SplitButton(_) => vec![toggle, menu],
+            Context::InputGroupPrepend => vec![div![C!["input-group-prepend"], toggle, menu]],
+            Context::InputGroupAppend => vec![div![C!["input-group-append"], toggle, menu]],
+        }
     }
 }
 
@@ -332,30 +851,457 @@ impl<Ms, ItemValue> Default for Dropdown<Ms, ItemValue> {
             items: Vec::new(),
             on_item_clicks: Vec::new(),
             toggle: Button::default(),
+            popper_options: PopperOptions::default(),
+            direction: Direction::Down,
+            align: Align::Left,
+            responsive_aligns: Vec::new(),
+            search: None,
+            selection: None,
+            on_selection_changes: Vec::new(),
+            selection_labels: SelectionLabels::default(),
+            open_on_hover: false,
+            hover_delays_ms: DEFAULT_HOVER_DELAYS_MS,
+            mega_menu: false,
+        }
+    }
+}
+
+fn menu_align_classes(align: Align, responsive_aligns: &[(Breakpoint, Align)]) -> Vec<String> {
+    let align = match align {
+        Align::Left => None,
+        Align::Right => Some("dropdown-menu-right".to_owned()),
+    };
+    align
+        .into_iter()
+        .chain(responsive_aligns.iter().map(|(breakpoint, align)| {
+            format!("dropdown-menu-{}-{}", breakpoint.as_str(), align.as_str())
+        }))
+        .collect()
+}
+
+fn view_items<Ms: 'static, ItemValue: Clone + 'static>(
+    items: Vec<Item<Ms, ItemValue>>,
+    search: Option<Search>,
+    mega_menu: bool,
+    selection_labels: &SelectionLabels,
+    context: &ItemContext<Ms, ItemValue>,
+) -> Vec<Node<Ms>> {
+    let model = context.model;
+    let to_msg = &context.to_msg;
+
+    let query = model.search.trim();
+    let items = match &search {
+        Some(search) if !query.is_empty() => items
+            .into_iter()
+            .filter_map(|item| item.filter(query, search.matcher.as_ref()))
+            .collect(),
+        _ => items,
+    };
+
+    let selection_actions = context.selection.map(|selection| {
+        let mut values = Vec::new();
+        selectable_values(&items, &mut values);
+        let mut select_all = button![
+            C!["dropdown-item", "text-center"],
+            attrs! {
+                At::Type => "button",
+                At::from(SELECTION_ACTION_ATTR) => AtValue::None,
+            },
+            &selection_labels.select_all,
+        ];
+        for handler in context.on_selection_change(selection.with_all(values)) {
+            select_all.add_event_handler(handler);
+        }
+        let mut clear = button![
+            C!["dropdown-item", "text-center"],
+            attrs! {
+                At::Type => "button",
+                At::from(SELECTION_ACTION_ATTR) => AtValue::None,
+            },
+            &selection_labels.clear,
+        ];
+        for handler in context.on_selection_change(Vec::new()) {
+            clear.add_event_handler(handler);
+        }
+        nodes![
+            div![C!["d-flex"], select_all, clear],
+            div![C!["dropdown-divider"]],
+        ]
+    });
+
+    let view_items = if mega_menu {
+        view_columns(items, context)
+    } else {
+        items
+            .into_iter()
+            .enumerate()
+            .map(|(index, item)| item.into_element(vec![index], context))
+            .collect::<Vec<_>>()
+    };
+
+    let search = match search {
+        Some(search) => search,
+        None => {
+            return selection_actions
+                .into_iter()
+                .flatten()
+                .chain(view_items)
+                .collect()
+        }
+    };
+
+    vec![
+        div![
+            C!["px-2", "pb-2"],
+            input![
+                el_ref(&model.search_input),
+                C!["form-control", "form-control-sm"],
+                attrs! {
+                    At::Type => "search",
+                    At::Value => model.search,
+                    At::Placeholder => search.placeholder,
+                    At::AutoComplete => "off",
+                    At::from("aria-label") => search.placeholder,
+                },
+                input_ev(Ev::Input, {
+                    let to_msg = Rc::clone(to_msg);
+                    move |search| to_msg(Msg::SearchChanged(search))
+                }),
+                keyboard_ev(Ev::KeyDown, {
+                    let to_msg = Rc::clone(to_msg);
+                    move |event: KeyboardEvent| {
+                        match event.key().as_str() {
+                            // Handled by the menu.
+                            "ArrowDown" | "ArrowUp" | "Escape" | "Tab" => {}
+                            "Enter" => {
+                                event.prevent_default();
+                                return Some(to_msg(Msg::ActivateFirstItem));
+                            }
+                            // Keep typing, Home, End, etc. in the input.
+                            _ => event.stop_propagation(),
+                        }
+                        None
+                    }
+                }),
+            ],
+        ],
+        div![
+            style! {
+                St::MaxHeight => rem(15),
+                St::OverflowY => "auto",
+                St::Position => "relative",
+            },
+            selection_actions,
+            if view_items.is_empty() {
+                vec![span![
+                    C!["dropdown-item-text", "text-muted"],
+                    search.no_results_text
+                ]]
+            } else {
+                view_items
+            },
+        ],
+    ]
+}
+
+// Every header starts a new column, the columns stack on small screens.
+fn view_columns<Ms: 'static, ItemValue: Clone + 'static>(
+    items: Vec<Item<Ms, ItemValue>>,
+    context: &ItemContext<Ms, ItemValue>,
+) -> Vec<Node<Ms>> {
+    let mut columns: Vec<Vec<Node<Ms>>> = Vec::new();
+    for (index, item) in items.into_iter().enumerate() {
+        let starts_column = match (&item, columns.last()) {
+            (_, None) => true,
+            (Item::Header(_), Some(column)) => !column.is_empty(),
+            _ => false,
+        };
+        if starts_column {
+            columns.push(Vec::new());
         }
+        if let Some(column) = columns.last_mut() {
+            column.push(item.into_element(vec![index], context));
+        }
+    }
+    if columns.is_empty() {
+        return Vec::new();
     }
+    vec![div![
+        C!["row", "mx-0"],
+        columns
+            .into_iter()
+            .map(|column| div![C!["col-md", "px-0"], column]),
+    ]]
+}
+
+fn summarize_selection<Ms, ItemValue>(
+    toggle: Button<Ms>,
+    items: &[Item<Ms, ItemValue>],
+    selection: Option<&Selection<ItemValue>>,
+    selection_labels: &SelectionLabels,
+) -> Button<Ms> {
+    let selection = match selection {
+        Some(selection) => selection,
+        None => return toggle,
+    };
+    let mut titles = Vec::new();
+    selected_titles(items, selection, &mut titles);
+    if titles.is_empty() {
+        toggle
+    } else {
+        toggle.title((selection_labels.summary)(&titles))
+    }
+}
+
+fn selected_titles<'a, Ms, ItemValue>(
+    items: &'a [Item<Ms, ItemValue>],
+    selection: &Selection<ItemValue>,
+    titles: &mut Vec<&'a str>,
+) {
+    for item in items {
+        match item {
+            Item::Button { title, value, .. } | Item::A { title, value, .. }
+                if selection.contains(value) =>
+            {
+                titles.push(title)
+            }
+            Item::Submenu { items, .. } => selected_titles(items, selection, titles),
+            _ => {}
+        }
+    }
+}
+
+fn selectable_values<Ms, ItemValue: Clone>(
+    items: &[Item<Ms, ItemValue>],
+    values: &mut Vec<ItemValue>,
+) {
+    for item in items {
+        match item {
+            Item::Button {
+                value,
+                disabled: false,
+                ..
+            }
+            | Item::A {
+                value,
+                disabled: false,
+                ..
+            } => values.push(value.clone()),
+            Item::Submenu {
+                items,
+                disabled: false,
+                ..
+            } => selectable_values(items, values),
+            _ => {}
+        }
+    }
+}
+
+// ------ ItemContext ------
+
+struct ItemContext<'a, Ms: 'static, ItemValue> {
+    model: &'a Model,
+    to_msg: Rc<dyn Fn(Msg) -> Ms>,
+    on_item_clicks: &'a [Rc<dyn Fn(MouseEvent, ItemValue) -> Ms>],
+    selection: Option<&'a Selection<ItemValue>>,
+    on_selection_changes: &'a [Rc<dyn Fn(Vec<ItemValue>) -> Ms>],
+}
+
+impl<'a, Ms: 'static, ItemValue: Clone + 'static> ItemContext<'a, Ms, ItemValue> {
+    fn on_selection_change(&self, selected: Vec<ItemValue>) -> Vec<EventHandler<Ms>> {
+        self.on_selection_changes
+            .iter()
+            .map(|on_selection_change| {
+                let on_selection_change = Rc::clone(on_selection_change);
+                let selected = selected.clone();
+                ev(Ev::Click, move |_| on_selection_change(selected))
+            })
+            .collect()
+    }
+}
+
+// ------ Selection ------
+
+struct Selection<ItemValue> {
+    selected: Vec<ItemValue>,
+    eq: fn(&ItemValue, &ItemValue) -> bool,
+}
+
+impl<ItemValue> Selection<ItemValue> {
+    fn contains(&self, value: &ItemValue) -> bool {
+        self.selected
+            .iter()
+            .any(|selected| (self.eq)(selected, value))
+    }
+}
+
+impl<ItemValue: Clone> Selection<ItemValue> {
+    fn toggled(&self, value: &ItemValue) -> Vec<ItemValue> {
+        if self.contains(value) {
+            self.selected
+                .iter()
+                .filter(|selected| !(self.eq)(selected, value))
+                .cloned()
+                .collect()
+        } else {
+            let mut selected = self.selected.clone();
+            selected.push(value.clone());
+            selected
+        }
+    }
+
+    fn with_all(&self, values: Vec<ItemValue>) -> Vec<ItemValue> {
+        let mut selected = self.selected.clone();
+        for value in values {
+            if !self.contains(&value) {
+                selected.push(value);
+            }
+        }
+        selected
+    }
+}
+
+// ------ SelectionLabels ------
+
+struct SelectionLabels {
+    summary: Rc<SelectionSummary>,
+    select_all: Cow<'static, str>,
+    clear: Cow<'static, str>,
+}
+
+impl Default for SelectionLabels {
+    fn default() -> Self {
+        Self {
+            summary: Rc::new(|titles| match titles {
+                [title] => (*title).to_owned(),
+                titles => format!("{} selected", titles.len()),
+            }),
+            select_all: "Select all".into(),
+            clear: "Clear".into(),
+        }
+    }
+}
+
+// ------ Context ------
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Context {
+    Standalone,
+    Nav,
+    ButtonGroup,
+    SplitButton(Cow<'static, str>),
+    InputGroupPrepend,
+    InputGroupAppend,
+}
+
+// ------ Direction ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Down,
+    Up,
+    Right,
+    Left,
+}
+
+impl Direction {
+    fn container_class(self) -> &'static str {
+        match self {
+            Self::Down => "dropdown",
+            Self::Up => "dropup",
+            Self::Right => "dropright",
+            Self::Left => "dropleft",
+        }
+    }
+
+    fn placement(self, align: Align) -> Placement {
+        match (self, align) {
+            (Self::Down, Align::Left) => Placement::BottomStart,
+            (Self::Down, Align::Right) => Placement::BottomEnd,
+            (Self::Up, Align::Left) => Placement::TopStart,
+            (Self::Up, Align::Right) => Placement::TopEnd,
+            (Self::Right, _) => Placement::RightStart,
+            (Self::Left, _) => Placement::LeftStart,
+        }
+    }
+}
+
+// ------ Align ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Right,
+}
+
+impl Align {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Left => "left",
+            Self::Right => "right",
+        }
+    }
+}
+
+// ------ Search ------
+
+struct Search {
+    placeholder: Cow<'static, str>,
+    no_results_text: Cow<'static, str>,
+    matcher: Rc<SearchMatcher>,
+}
+
+impl Default for Search {
+    fn default() -> Self {
+        Self {
+            placeholder: "Search".into(),
+            no_results_text: "No results".into(),
+            matcher: Rc::new(|query, title| title.to_lowercase().contains(&query.to_lowercase())),
+        }
+    }
+}
+
+// ------ DropdownItems ------
+
+// Items generated from an enum's variants, see `#[derive(DropdownItems)]` (feature `derive`).
+pub trait DropdownItems: Sized {
+    fn dropdown_items<Ms: 'static>() -> Vec<Item<Ms, Self>>;
 }
 
 // ------ Item ------
 
-pub enum Item<ItemValue> {
+pub enum Item<Ms: 'static, ItemValue> {
     Button {
         title: Cow<'static, str>,
         value: ItemValue,
+        disabled: bool,
+        active: bool,
     },
     A {
         title: Cow<'static, str>,
         value: ItemValue,
         href: Cow<'static, str>,
+        disabled: bool,
+        active: bool,
+    },
+    Header(Cow<'static, str>),
+    Text(Cow<'static, str>),
+    Custom(Box<Node<Ms>>),
+    Submenu {
+        title: Cow<'static, str>,
+        items: Vec<Item<Ms, ItemValue>>,
+        disabled: bool,
     },
     Divider,
 }
 
-impl<ItemValue> Item<ItemValue> {
+impl<Ms, ItemValue> Item<Ms, ItemValue> {
     pub fn button(title: impl Into<Cow<'static, str>>, value: ItemValue) -> Self {
         Self::Button {
             title: title.into(),
             value,
+            disabled: false,
+            active: false,
         }
     }
 
@@ -368,52 +1314,307 @@ impl<ItemValue> Item<ItemValue> {
             title: title.into(),
             value,
             href: href.into(),
+            disabled: false,
+            active: false,
+        }
+    }
+
+    pub fn header(title: impl Into<Cow<'static, str>>) -> Self {
+        Self::Header(title.into())
+    }
+
+    pub fn text(text: impl Into<Cow<'static, str>>) -> Self {
+        Self::Text(text.into())
+    }
+
+    pub fn custom(node: Node<Ms>) -> Self {
+        Self::Custom(Box::new(node))
+    }
+
+    pub fn submenu(title: impl Into<Cow<'static, str>>, items: Vec<Item<Ms, ItemValue>>) -> Self {
+        Self::Submenu {
+            title: title.into(),
+            items,
+            disabled: false,
         }
     }
 
     pub fn divider() -> Self {
         Self::Divider
     }
+
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        if let Self::Button { disabled, .. }
+        | Self::A { disabled, .. }
+        | Self::Submenu { disabled, .. } = &mut self
+        {
+            *disabled = is_disabled;
+        }
+        self
+    }
+
+    pub fn active(mut self, is_active: bool) -> Self {
+        if let Self::Button { active, .. } | Self::A { active, .. } = &mut self {
+            *active = is_active;
+        }
+        self
+    }
+
+    // Marks link items active, links in submenus included.
+    pub fn active_for_url(self, url: &Url, url_match: UrlMatch) -> Self {
+        match self {
+            Self::A { ref href, .. } => {
+                let active = url_match.matches(href, url);
+                self.active(active)
+            }
+            Self::Submenu {
+                title,
+                items,
+                disabled,
+            } => Self::Submenu {
+                title,
+                items: items
+                    .into_iter()
+                    .map(|item| item.active_for_url(url, url_match))
+                    .collect(),
+                disabled,
+            },
+            _ => self,
+        }
+    }
 }
 
-impl<ItemValue: Clone + 'static> Item<ItemValue> {
-    fn into_element<Ms: 'static>(
-        self,
-        to_msg: impl Fn(Msg) -> Ms + Clone + 'static,
-        on_item_clicks: Vec<Rc<dyn Fn(MouseEvent, ItemValue) -> Ms>>,
-    ) -> Node<Ms> {
+impl<Ms: 'static, ItemValue: Clone + 'static> Item<Ms, ItemValue> {
+    // Keeps matching actions, and submenus that match or contain a match.
+    fn filter(self, query: &str, matcher: &SearchMatcher) -> Option<Self> {
         match self {
-            Self::Button { title, value } => {
-                let mut node = button![
-                    C!["dropdown-item"],
-                    attrs! {At::Type => "button"},
-                    title,
-                    ev(Ev::Click, move |_| to_msg(Msg::Collapse(None))),
-                ];
-                for on_item_click in on_item_clicks {
-                    node.add_event_handler(mouse_ev(Ev::Click, {
-                        let value = value.clone();
-                        move |event| on_item_click(event, value)
-                    }));
+            Self::Button { ref title, .. } | Self::A { ref title, .. } => {
+                if matcher(query, title) {
+                    Some(self)
+                } else {
+                    None
+                }
+            }
+            Self::Submenu {
+                title,
+                items,
+                disabled,
+            } => {
+                let items = if matcher(query, &title) {
+                    items
+                } else {
+                    items
+                        .into_iter()
+                        .filter_map(|item| item.filter(query, matcher))
+                        .collect::<Vec<_>>()
+                };
+                if items.is_empty() {
+                    None
+                } else {
+                    Some(Self::Submenu {
+                        title,
+                        items,
+                        disabled,
+                    })
                 }
-                node
             }
-            Self::A { title, value, href } => {
-                let mut node = a![
-                    C!["dropdown-item"],
-                    attrs! {At::Href => href},
+            Self::Header(_) | Self::Text(_) | Self::Custom(_) | Self::Divider => None,
+        }
+    }
+
+    fn into_element(self, path: Vec<usize>, context: &ItemContext<Ms, ItemValue>) -> Node<Ms> {
+        let depth = path.len() - 1;
+        let selected = |value: &ItemValue| context.selection.map(|s| s.contains(value));
+        let (mut node, value, disabled) = match self {
+            Self::Button {
+                title,
+                value,
+                disabled,
+                active,
+            } => (
+                button![
+                    C![
+                        "dropdown-item",
+                        IF!(disabled => "disabled"),
+                        IF!(active => "active")
+                    ],
+                    attrs! {
+                        At::Type => "button",
+                        At::Disabled => disabled.as_at_value(),
+                    },
+                    item_state_attrs(disabled, IF!(active => "true")),
+                    selected(&value).map(view_checkbox),
                     title,
-                    ev(Ev::Click, move |_| to_msg(Msg::Collapse(None))),
-                ];
-                for on_item_click in on_item_clicks {
-                    node.add_event_handler(mouse_ev(Ev::Click, {
-                        let value = value.clone();
-                        move |event| on_item_click(event, value)
-                    }));
+                ],
+                value,
+                disabled,
+            ),
+            Self::A {
+                title,
+                value,
+                href,
+                disabled,
+                active,
+            } => (
+                a![
+                    C![
+                        "dropdown-item",
+                        IF!(disabled => "disabled"),
+                        IF!(active => "active")
+                    ],
+                    attrs! {
+                        At::Href => href,
+                        At::TabIndex => if disabled { AtValue::Some((-1).to_string()) } else { AtValue::Ignored },
+                    },
+                    item_state_attrs(disabled, IF!(active => "page")),
+                    selected(&value).map(view_checkbox),
+                    title,
+                ],
+                value,
+                disabled,
+            ),
+            Self::Header(title) => return h6![C!["dropdown-header"], title],
+            Self::Text(text) => return span![C!["dropdown-item-text"], text],
+            Self::Custom(node) => return *node,
+            Self::Submenu {
+                title,
+                items,
+                disabled,
+            } => return view_submenu(title, items, disabled, path, context),
+            Self::Divider => return div![C!["dropdown-divider"]],
+        };
+        let to_msg = &context.to_msg;
+        if context.model.submenus.len() > depth {
+            node.add_event_handler(ev(Ev::MouseEnter, {
+                let to_msg = Rc::clone(to_msg);
+                move |_| to_msg(Msg::CloseSubmenu(depth))
+            }));
+        }
+        if disabled {
+            return node;
+        }
+        match context.selection {
+            // Multi-select items toggle their value and keep the menu open.
+            Some(selection) => {
+                node.add_attr("role", "menuitemcheckbox");
+                node.add_attr("aria-checked", selection.contains(&value).to_string());
+                node.add_event_handler(ev(Ev::Click, |event| event.prevent_default()));
+                for handler in context.on_selection_change(selection.toggled(&value)) {
+                    node.add_event_handler(handler);
                 }
-                node
             }
-            Self::Divider => div![C!["dropdown-divider"]],
+            None => {
+                let to_msg = Rc::clone(to_msg);
+                node.add_event_handler(ev(Ev::Click, move |_| to_msg(Msg::Collapse(None))));
+            }
+        }
+        for on_item_click in context.on_item_clicks {
+            node.add_event_handler(mouse_ev(Ev::Click, {
+                let on_item_click = Rc::clone(on_item_click);
+                let value = value.clone();
+                move |event| on_item_click(event, value)
+            }));
         }
+        node
+    }
+}
+
+fn view_checkbox<Ms>(checked: bool) -> Node<Ms> {
+    input![
+        C!["mr-2"],
+        style! {St::PointerEvents => "none"},
+        attrs! {
+            At::Type => "checkbox",
+            At::Checked => checked.as_at_value(),
+            At::TabIndex => -1,
+            At::from("aria-hidden") => "true",
+        },
+    ]
+}
+
+fn view_submenu<Ms: 'static, ItemValue: Clone + 'static>(
+    title: Cow<'static, str>,
+    items: Vec<Item<Ms, ItemValue>>,
+    disabled: bool,
+    path: Vec<usize>,
+    context: &ItemContext<Ms, ItemValue>,
+) -> Node<Ms> {
+    let depth = path.len() - 1;
+    let model = context.model;
+    let to_msg = &context.to_msg;
+    let submenu = if model.is_submenu_open(&path) {
+        model.submenus.get(depth)
+    } else {
+        None
+    };
+
+    let mut trigger = button![
+        submenu.map(|submenu| el_ref(&submenu.trigger)),
+        C![
+            "dropdown-item",
+            "dropdown-toggle",
+            IF!(disabled => "disabled")
+        ],
+        attrs! {
+            At::Type => "button",
+            At::Disabled => disabled.as_at_value(),
+            At::from("aria-haspopup") => "true",
+            At::from("aria-expanded") => submenu.is_some(),
+        },
+        item_state_attrs(disabled, None),
+        title,
+    ];
+    if !disabled {
+        trigger.add_event_handler(ev(Ev::MouseEnter, {
+            let (to_msg, path) = (Rc::clone(to_msg), path.clone());
+            move |_| to_msg(Msg::OpenSubmenu(path))
+        }));
+        trigger.add_event_handler(ev(Ev::Click, {
+            let (to_msg, path) = (Rc::clone(to_msg), path.clone());
+            move |_| to_msg(Msg::OpenSubmenu(path))
+        }));
+        trigger.add_event_handler(keyboard_ev(Ev::KeyDown, {
+            let (to_msg, path) = (Rc::clone(to_msg), path.clone());
+            move |event: KeyboardEvent| {
+                if event.key() != "ArrowRight" {
+                    return None;
+                }
+                event.prevent_default();
+                Some(to_msg(Msg::OpenSubmenuAndFocus(path)))
+            }
+        }));
+    }
+
+    let popup = div![
+        submenu.map(|submenu| el_ref(&submenu.popup)),
+        C!["dropdown-menu", IF!(submenu.is_some() => "show")],
+        attrs! {
+            At::Style => submenu.map_or("", |submenu| &submenu.popup_style),
+        },
+        keyboard_ev(Ev::KeyDown, {
+            let to_msg = Rc::clone(to_msg);
+            move |event: KeyboardEvent| {
+                if event.key() != "ArrowLeft" {
+                    return None;
+                }
+                event.prevent_default();
+                event.stop_propagation();
+                Some(to_msg(Msg::CloseSubmenuAndFocusTrigger(depth)))
+            }
+        }),
+        items.into_iter().enumerate().map(|(index, item)| {
+            let mut path = path.clone();
+            path.push(index);
+            item.into_element(path, context)
+        }),
+    ];
+
+    div![C!["dropright"], trigger, popup]
+}
+
+fn item_state_attrs(disabled: bool, current: Option<&str>) -> Attrs {
+    attrs! {
+        At::from("aria-disabled") => if disabled { AtValue::Some(true.to_string()) } else { AtValue::Ignored },
+        At::from("aria-current") => current.map_or(AtValue::Ignored, |current| AtValue::Some(current.to_owned())),
     }
 }
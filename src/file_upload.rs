@@ -0,0 +1,483 @@
+use seed::{prelude::*, *};
+use std::{borrow::Cow, rc::Rc};
+use uuid::Uuid;
+use wasm_bindgen::JsCast;
+use web_sys::{File, FileList, HtmlInputElement};
+
+// ------ ------
+//     Init
+// ------ ------
+
+pub fn init() -> Model {
+    Model {
+        uploads: Vec::new(),
+        errors: Vec::new(),
+        limits: Limits::default(),
+        dragging: false,
+        input: ElRef::default(),
+        id: Uuid::new_v4().to_string(),
+    }
+}
+
+// ------ ------
+//     Model
+// ------ ------
+
+pub struct Model {
+    uploads: Vec<Upload>,
+    errors: Vec<String>,
+    limits: Limits,
+    dragging: bool,
+    input: ElRef<HtmlInputElement>,
+    id: String,
+}
+
+impl Model {
+    pub fn accept(mut self, accept: Vec<impl Into<Cow<'static, str>>>) -> Self {
+        self.limits.accept = accept.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn max_size(mut self, bytes: f64) -> Self {
+        self.limits.max_size = Some(bytes);
+        self
+    }
+
+    pub fn max_count(mut self, max_count: usize) -> Self {
+        self.limits.max_count = Some(max_count);
+        self
+    }
+
+    pub fn files(&self) -> impl Iterator<Item = &File> {
+        self.uploads.iter().map(|upload| &upload.file)
+    }
+
+    pub fn progress(&self, file: &File) -> Option<f64> {
+        self.uploads
+            .iter()
+            .find(|upload| &upload.file == file)
+            .and_then(|upload| upload.progress)
+    }
+
+    pub fn set_progress(&mut self, file: &File, percent: f64) {
+        if let Some(upload) = self.upload_mut(file) {
+            upload.progress = Some(if percent > 100. {
+                100.
+            } else {
+                percent.max(0.)
+            });
+            upload.error = None;
+        }
+    }
+
+    pub fn set_failed(&mut self, file: &File, error: impl Into<String>) {
+        if let Some(upload) = self.upload_mut(file) {
+            upload.error = Some(error.into());
+        }
+    }
+
+    pub fn remove(&mut self, file: &File) {
+        self.uploads.retain(|upload| &upload.file != file);
+    }
+
+    pub fn clear(&mut self) {
+        self.uploads.clear();
+        self.errors.clear();
+    }
+
+    fn upload_mut(&mut self, file: &File) -> Option<&mut Upload> {
+        self.uploads.iter_mut().find(|upload| &upload.file == file)
+    }
+}
+
+// ------ Upload ------
+
+struct Upload {
+    file: File,
+    preview_url: Option<String>,
+    progress: Option<f64>,
+    error: Option<String>,
+}
+
+impl Upload {
+    fn new(file: File) -> Self {
+        let preview_url = if file.type_().starts_with("image/") {
+            web_sys::Url::create_object_url_with_blob(&file).ok()
+        } else {
+            None
+        };
+        Self {
+            file,
+            preview_url,
+            progress: None,
+            error: None,
+        }
+    }
+}
+
+impl Drop for Upload {
+    fn drop(&mut self) {
+        if let Some(preview_url) = &self.preview_url {
+            let _ = web_sys::Url::revoke_object_url(preview_url);
+        }
+    }
+}
+
+// ------ Limits ------
+
+#[derive(Clone, Default)]
+struct Limits {
+    accept: Vec<Cow<'static, str>>,
+    max_size: Option<f64>,
+    max_count: Option<usize>,
+}
+
+impl Limits {
+    fn validate(&self, existing_count: usize, files: Vec<File>) -> (Vec<File>, Vec<String>) {
+        let mut accepted = Vec::new();
+        let mut errors = Vec::new();
+        for file in files {
+            if !self.is_accepted(&file) {
+                errors.push(format!("{}: file type is not allowed.", file.name()));
+            } else if matches!(self.max_size, Some(max_size) if file.size() > max_size) {
+                errors.push(format!(
+                    "{}: file is larger than {}.",
+                    file.name(),
+                    format_size(self.max_size.unwrap_or_default())
+                ));
+            } else if matches!(
+                self.max_count,
+                Some(max_count) if existing_count + accepted.len() >= max_count
+            ) {
+                errors.push(format!("{}: too many files.", file.name()));
+            } else {
+                accepted.push(file);
+            }
+        }
+        (accepted, errors)
+    }
+
+    fn is_accepted(&self, file: &File) -> bool {
+        if self.accept.is_empty() {
+            return true;
+        }
+        let (name, type_) = (file.name().to_lowercase(), file.type_());
+        self.accept.iter().any(|accept| {
+            let accept = accept.trim().to_lowercase();
+            if accept.starts_with('.') {
+                name.ends_with(&accept)
+            } else if let Some(prefix) = accept.strip_suffix('*') {
+                type_.starts_with(prefix)
+            } else {
+                type_ == accept
+            }
+        })
+    }
+}
+
+// ------ ------
+//    Update
+// ------ ------
+
+#[derive(Debug)]
+pub enum Msg {
+    FilesSelected(Vec<File>),
+    DragEntered,
+    DragLeft,
+    Remove(usize),
+}
+
+pub fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {
+    match msg {
+        Msg::FilesSelected(files) => {
+            model.dragging = false;
+            let (accepted, errors) = model.limits.validate(model.uploads.len(), files);
+            model.errors = errors;
+            model.uploads.extend(accepted.into_iter().map(Upload::new));
+            // The app's `on_select` handlers of the same change event still read the input's files.
+            let input = model.input.clone();
+            orders.after_next_render(move |_| {
+                if let Some(input) = input.get() {
+                    input.set_value("");
+                }
+            });
+        }
+        Msg::DragEntered => model.dragging = true,
+        Msg::DragLeft => model.dragging = false,
+        Msg::Remove(index) => {
+            if index < model.uploads.len() {
+                model.uploads.remove(index);
+            }
+        }
+    }
+}
+
+// ------ ------
+//     View
+// ------ ------
+
+// ------ FileUpload ------
+
+pub struct FileUpload<Ms: 'static> {
+    id: Option<Cow<'static, str>>,
+    label: Cow<'static, str>,
+    drop_zone_content: Vec<Node<Ms>>,
+    on_selects: Vec<Rc<dyn Fn(Vec<File>) -> Ms>>,
+    attrs: Attrs,
+}
+
+impl<Ms: 'static> FileUpload<Ms> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn id(mut self, id: impl Into<Cow<'static, str>>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn label(mut self, label: impl Into<Cow<'static, str>>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    pub fn drop_zone_content(mut self, content: impl IntoNodes<Ms>) -> Self {
+        self.drop_zone_content = content.into_nodes();
+        self
+    }
+
+    pub fn add_on_select(
+        mut self,
+        on_select: impl FnOnce(Vec<File>) -> Ms + Clone + 'static,
+    ) -> Self {
+        self.on_selects
+            .push(Rc::new(move |files| on_select.clone()(files)));
+        self
+    }
+
+    pub fn add_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs.merge(attrs);
+        self
+    }
+
+    pub fn view(self, model: &Model, to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static) -> Node<Ms> {
+        let to_msg = move |msg| to_msg.clone()(msg);
+        let id = self.id.unwrap_or_else(|| model.id.clone().into());
+        let multiple = !matches!(model.limits.max_count, Some(max_count) if max_count <= 1);
+
+        let mut drop_zone = div![
+            C![
+                "border",
+                "rounded",
+                "p-4",
+                "text-center",
+                "text-muted",
+                IF!(model.dragging => "border-primary"),
+                IF!(model.dragging => "bg-light"),
+            ],
+            style! {St::BorderStyle => "dashed"},
+            self.drop_zone_content,
+            drag_ev(Ev::DragOver, |event| event.prevent_default()),
+            drag_ev(Ev::DragEnter, {
+                let to_msg = to_msg.clone();
+                move |event| {
+                    event.prevent_default();
+                    to_msg(Msg::DragEntered)
+                }
+            }),
+            drag_ev(Ev::DragLeave, {
+                let to_msg = to_msg.clone();
+                move |event| {
+                    // Moving onto a child of the drop zone leaves the zone itself too.
+                    let zone = event
+                        .current_target()
+                        .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+                    let entered = event
+                        .related_target()
+                        .and_then(|target| target.dyn_into::<web_sys::Node>().ok());
+                    let is_inside = matches!(&zone, Some(zone) if zone.contains(entered.as_ref()));
+                    IF!(!is_inside => to_msg(Msg::DragLeft))
+                }
+            }),
+        ];
+        let mut input = input![
+            el_ref(&model.input),
+            C![
+                "custom-file-input",
+                IF!(!model.errors.is_empty() => "is-invalid")
+            ],
+            id!(id.clone()),
+            attrs! {
+                At::Type => "file",
+                At::Multiple => multiple.as_at_value(),
+                At::Accept => if model.limits.accept.is_empty() {
+                    AtValue::Ignored
+                } else {
+                    AtValue::Some(model.limits.accept.join(","))
+                },
+            },
+        ];
+
+        // Seed dispatches handlers in order, so files are in the model before the app is notified.
+        drop_zone.add_event_handler(drag_ev(Ev::Drop, {
+            let to_msg = to_msg.clone();
+            move |event| {
+                event.prevent_default();
+                let files = to_files(event.data_transfer().and_then(|data| data.files()));
+                to_msg(Msg::FilesSelected(files))
+            }
+        }));
+        input.add_event_handler(ev(Ev::Change, {
+            let to_msg = to_msg.clone();
+            move |event| to_msg(Msg::FilesSelected(to_files(event_target_files(&event))))
+        }));
+        for on_select in self.on_selects {
+            let limits = model.limits.clone();
+            let existing_count = model.uploads.len();
+            drop_zone.add_event_handler(drag_ev(Ev::Drop, {
+                let (limits, on_select) = (limits.clone(), on_select.clone());
+                move |event| {
+                    let files = to_files(event.data_transfer()?.files());
+                    Some(on_select(limits.validate(existing_count, files).0))
+                }
+            }));
+            input.add_event_handler(ev(Ev::Change, move |event| {
+                let files = to_files(event_target_files(&event));
+                on_select(limits.validate(existing_count, files).0)
+            }));
+        }
+
+        div![
+            self.attrs,
+            drop_zone,
+            div![
+                C!["custom-file", "mt-2"],
+                input,
+                label![C!["custom-file-label"], attrs! {At::For => id}, self.label],
+                model
+                    .errors
+                    .iter()
+                    .map(|error| div![C!["invalid-feedback"], error]),
+            ],
+            ul![
+                C!["list-unstyled", "mt-3", "mb-0"],
+                model.uploads.iter().enumerate().map(|(index, upload)| {
+                    view_upload(upload, {
+                        let to_msg = to_msg.clone();
+                        move |_| to_msg(Msg::Remove(index))
+                    })
+                }),
+            ],
+        ]
+    }
+}
+
+impl<Ms> Default for FileUpload<Ms> {
+    fn default() -> Self {
+        Self {
+            id: None,
+            label: "Choose files".into(),
+            drop_zone_content: vec![Node::new_text("Drag and drop files here")],
+            on_selects: Vec::new(),
+            attrs: Attrs::empty(),
+        }
+    }
+}
+
+fn view_upload<Ms: 'static>(
+    upload: &Upload,
+    on_remove: impl FnOnce(web_sys::Event) -> Ms + Clone + 'static,
+) -> Node<Ms> {
+    li![
+        C!["media", "mb-2"],
+        match &upload.preview_url {
+            Some(preview_url) => img![
+                C!["mr-3", "rounded"],
+                attrs! {
+                    At::Src => preview_url,
+                    At::Alt => upload.file.name(),
+                    At::Width => 64,
+                    At::Height => 64,
+                },
+                style! {St::ObjectFit => "cover"},
+            ],
+            None => div![
+                C!["mr-3", "rounded", "bg-light", "border"],
+                style! {St::Width => px(64), St::Height => px(64)},
+            ],
+        },
+        div![
+            C!["media-body"],
+            div![
+                C!["d-flex", "justify-content-between", "align-items-start"],
+                div![
+                    C!["text-break"],
+                    upload.file.name(),
+                    small![C!["text-muted", "ml-2"], format_size(upload.file.size())],
+                ],
+                button![
+                    C!["close"],
+                    attrs! {
+                        At::Type => "button",
+                        At::from("aria-label") => format!("Remove {}", upload.file.name()),
+                    },
+                    span![attrs! {At::from("aria-hidden") => "true"}, "×"],
+                    ev(Ev::Click, on_remove),
+                ],
+            ],
+            upload.progress.map(|progress| {
+                div![
+                    C!["progress", "mt-1"],
+                    style! {St::Height => px(6)},
+                    div![
+                        C![
+                            "progress-bar",
+                            IF!(upload.error.is_some() => "bg-danger"),
+                            IF!(progress >= 100. => "bg-success"),
+                        ],
+                        style! {St::Width => format!("{}%", progress)},
+                        attrs! {
+                            At::from("role") => "progressbar",
+                            At::from("aria-valuenow") => progress,
+                            At::from("aria-valuemin") => 0,
+                            At::from("aria-valuemax") => 100,
+                        },
+                    ],
+                ]
+            }),
+            upload
+                .error
+                .as_ref()
+                .map(|error| small![C!["text-danger"], error]),
+        ],
+    ]
+}
+
+fn event_target_files(event: &web_sys::Event) -> Option<FileList> {
+    event.target()?.dyn_into::<HtmlInputElement>().ok()?.files()
+}
+
+fn to_files(file_list: Option<FileList>) -> Vec<File> {
+    file_list
+        .map(|file_list| {
+            (0..file_list.length())
+                .filter_map(|index| file_list.get(index))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn format_size(bytes: f64) -> String {
+    const UNITS: &[&str] = &["B", "KB", "MB", "GB"];
+    let mut size = bytes;
+    let mut unit = 0;
+    while size >= 1024. && unit + 1 < UNITS.len() {
+        size /= 1024.;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{} {}", size, UNITS[unit])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit])
+    }
+}
@@ -0,0 +1,190 @@
+use super::{
+    dropdown, file_upload, navbar, offcanvas, priority_nav, scrollspy, tag_input, typeahead,
+};
+use seed::{app::OrdersProxy, prelude::*, *};
+use std::{fmt::Display, ops::Index};
+
+// ------ Component ------
+
+// A stateful component that can be managed by `Instances`.
+pub trait Component {
+    type Msg: 'static;
+
+    fn update(msg: Self::Msg, model: &mut Self, orders: &mut impl Orders<Self::Msg>);
+}
+
+impl Component for dropdown::Model {
+    type Msg = dropdown::Msg;
+
+    fn update(msg: Self::Msg, model: &mut Self, orders: &mut impl Orders<Self::Msg>) {
+        dropdown::update(msg, model, orders)
+    }
+}
+
+impl Component for file_upload::Model {
+    type Msg = file_upload::Msg;
+
+    fn update(msg: Self::Msg, model: &mut Self, orders: &mut impl Orders<Self::Msg>) {
+        file_upload::update(msg, model, orders)
+    }
+}
+
+impl Component for navbar::Model {
+    type Msg = navbar::Msg;
+
+    fn update(msg: Self::Msg, model: &mut Self, orders: &mut impl Orders<Self::Msg>) {
+        navbar::update(msg, model, orders)
+    }
+}
+
+impl Component for offcanvas::Model {
+    type Msg = offcanvas::Msg;
+
+    fn update(msg: Self::Msg, model: &mut Self, orders: &mut impl Orders<Self::Msg>) {
+        offcanvas::update(msg, model, orders)
+    }
+}
+
+impl Component for priority_nav::Model {
+    type Msg = priority_nav::Msg;
+
+    fn update(msg: Self::Msg, model: &mut Self, orders: &mut impl Orders<Self::Msg>) {
+        priority_nav::update(msg, model, orders)
+    }
+}
+
+impl Component for scrollspy::Model {
+    type Msg = scrollspy::Msg;
+
+    fn update(msg: Self::Msg, model: &mut Self, orders: &mut impl Orders<Self::Msg>) {
+        scrollspy::update(msg, model, orders)
+    }
+}
+
+impl Component for tag_input::Model {
+    type Msg = tag_input::Msg;
+
+    fn update(msg: Self::Msg, model: &mut Self, orders: &mut impl Orders<Self::Msg>) {
+        tag_input::update(msg, model, orders)
+    }
+}
+
+impl<T: Display + Clone + 'static> Component for typeahead::Model<T> {
+    type Msg = typeahead::Msg<T>;
+
+    fn update(msg: Self::Msg, model: &mut Self, orders: &mut impl Orders<Self::Msg>) {
+        typeahead::update(msg, model, orders)
+    }
+}
+
+// ------ Instances ------
+
+// Component models identified by keys, in insertion order.
+// Messages are routed through `to_msg`, usually an enum variant like `Msg::DropdownMsg`.
+pub struct Instances<K, M: Component, Ms> {
+    instances: Vec<(K, M)>,
+    to_msg: fn(M::Msg, K) -> Ms,
+}
+
+impl<K: PartialEq + Clone + 'static, M: Component, Ms: 'static> Instances<K, M, Ms> {
+    pub fn new(to_msg: fn(M::Msg, K) -> Ms) -> Self {
+        Self {
+            instances: Vec::new(),
+            to_msg,
+        }
+    }
+
+    pub fn init<O: Orders<Ms>>(
+        to_msg: fn(M::Msg, K) -> Ms,
+        keys: impl IntoIterator<Item = K>,
+        orders: &mut O,
+        mut init: impl FnMut(&K, &mut OrdersProxy<M::Msg, O::AppMs, O::Mdl, O::INodes>) -> M,
+    ) -> Self {
+        let mut instances = Self::new(to_msg);
+        for key in keys {
+            instances.add(key, orders, &mut init);
+        }
+        instances
+    }
+
+    // Replaces the model if the key is already present.
+    pub fn add<O: Orders<Ms>>(
+        &mut self,
+        key: K,
+        orders: &mut O,
+        init: impl FnOnce(&K, &mut OrdersProxy<M::Msg, O::AppMs, O::Mdl, O::INodes>) -> M,
+    ) {
+        let model = init(&key, &mut orders.proxy(self.to_msg(&key)));
+        match self.instances.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, old_model)) => *old_model = model,
+            None => self.instances.push((key, model)),
+        }
+    }
+
+    pub fn remove(&mut self, key: &K) -> Option<M> {
+        let index = self.instances.iter().position(|(k, _)| k == key)?;
+        Some(self.instances.remove(index).1)
+    }
+
+    // Messages for removed instances are ignored.
+    pub fn update(&mut self, msg: M::Msg, key: K, orders: &mut impl Orders<Ms>) {
+        let to_msg = self.to_msg(&key);
+        if let Some(model) = self.get_mut(&key) {
+            M::update(msg, model, &mut orders.proxy(to_msg));
+        }
+    }
+
+    pub fn view(&self, key: &K, view: impl FnOnce(&M) -> Node<Ms>) -> Node<Ms> {
+        match self.get(key) {
+            Some(model) => view(model),
+            None => empty![],
+        }
+    }
+
+    // Maps the component messages of the given instance, pass it to the component's view.
+    pub fn to_msg(&self, key: &K) -> impl FnOnce(M::Msg) -> Ms + Clone + 'static {
+        let to_msg = self.to_msg;
+        let key = key.clone();
+        move |msg| to_msg(msg, key)
+    }
+
+    pub fn get(&self, key: &K) -> Option<&M> {
+        self.instances
+            .iter()
+            .find_map(|(k, model)| if k == key { Some(model) } else { None })
+    }
+
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut M> {
+        self.instances
+            .iter_mut()
+            .find_map(|(k, model)| if k == key { Some(model) } else { None })
+    }
+
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.instances.iter().map(|(key, _)| key)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &M)> {
+        self.instances.iter().map(|(key, model)| (key, model))
+    }
+
+    pub fn len(&self) -> usize {
+        self.instances.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.instances.is_empty()
+    }
+}
+
+impl<K: PartialEq + Clone + 'static, M: Component, Ms: 'static> Index<&K> for Instances<K, M, Ms> {
+    type Output = M;
+
+    fn index(&self, key: &K) -> &M {
+        self.get(key).expect("no instance for the given key")
+    }
+}
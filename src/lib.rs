@@ -1,5 +1,20 @@
+pub mod breakpoint;
 pub mod button;
 pub mod button_group;
+mod dismiss;
 pub mod dropdown;
+pub mod file_upload;
 pub mod form_group;
+pub mod instances;
+pub mod nav;
 pub mod navbar;
+mod navigation;
+pub mod offcanvas;
+mod popper;
+#[cfg(not(feature = "popper"))]
+mod position;
+pub mod priority_nav;
+pub mod scrollspy;
+pub mod tag_input;
+pub mod typeahead;
+mod url_match;
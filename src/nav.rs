@@ -0,0 +1,155 @@
+pub use super::navbar::NavLink;
+use seed::virtual_dom::IntoNodes;
+use seed::{prelude::*, *};
+
+// ------ Nav ------
+
+// A `.nav` list of `NavLink`s and dropdowns rendered with `Dropdown::view_in_nav`.
+pub struct Nav<Ms: 'static> {
+    content: Vec<Node<Ms>>,
+    look: Look,
+    vertical: bool,
+    width: Width,
+    card_header: bool,
+    attrs: Attrs,
+    style: Style,
+}
+
+impl<Ms> UpdateEl<Ms> for Nav<Ms> {
+    fn update_el(self, el: &mut El<Ms>) {
+        self.view().update_el(el)
+    }
+}
+
+impl<Ms> Nav<Ms> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn content(mut self, content: impl IntoNodes<Ms>) -> Self {
+        self.content = content.into_nodes();
+        self
+    }
+
+    // --- look ---
+
+    fn look(mut self, look: Look) -> Self {
+        self.look = look;
+        self
+    }
+
+    pub fn tabs(self) -> Self {
+        self.look(Look::Tabs)
+    }
+
+    pub fn pills(self) -> Self {
+        self.look(Look::Pills)
+    }
+
+    // --- layout ---
+
+    pub fn vertical(mut self) -> Self {
+        self.vertical = true;
+        self
+    }
+
+    // Items fill the available width, each one as wide as its content.
+    pub fn fill(mut self) -> Self {
+        self.width = Width::Fill;
+        self
+    }
+
+    // Items fill the available width, all of them equally wide.
+    pub fn justified(mut self) -> Self {
+        self.width = Width::Justified;
+        self
+    }
+
+    // Aligns tabs or pills with the borders of a `.card-header`.
+    pub fn card_header(mut self) -> Self {
+        self.card_header = true;
+        self
+    }
+
+    pub fn add_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs.merge(attrs);
+        self
+    }
+
+    pub fn add_style(mut self, style: Style) -> Self {
+        self.style.merge(style);
+        self
+    }
+
+    pub fn view(self) -> Node<Ms> {
+        let card_header_class = match (self.card_header, self.look) {
+            (true, Look::Tabs) => Some("card-header-tabs"),
+            (true, Look::Pills) => Some("card-header-pills"),
+            _ => None,
+        };
+        ul![
+            C![
+                "nav",
+                self.look.as_class(),
+                IF!(self.vertical => "flex-column"),
+                self.width.as_class(),
+                card_header_class,
+            ],
+            self.style,
+            self.attrs,
+            self.content,
+        ]
+    }
+}
+
+impl<Ms> Default for Nav<Ms> {
+    fn default() -> Self {
+        Self {
+            content: Vec::new(),
+            look: Look::Links,
+            vertical: false,
+            width: Width::Auto,
+            card_header: false,
+            attrs: Attrs::empty(),
+            style: Style::empty(),
+        }
+    }
+}
+
+// ------ Look ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Look {
+    Links,
+    Tabs,
+    Pills,
+}
+
+impl Look {
+    fn as_class(self) -> Option<&'static str> {
+        match self {
+            Self::Links => None,
+            Self::Tabs => Some("nav-tabs"),
+            Self::Pills => Some("nav-pills"),
+        }
+    }
+}
+
+// ------ Width ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Width {
+    Auto,
+    Fill,
+    Justified,
+}
+
+impl Width {
+    fn as_class(self) -> Option<&'static str> {
+        match self {
+            Self::Auto => None,
+            Self::Fill => Some("nav-fill"),
+            Self::Justified => Some("nav-justified"),
+        }
+    }
+}
@@ -1,11 +1,65 @@
-use super::button::Button;
+use super::button::{self, Button};
+use super::form_group::FormGroup;
+use super::navigation::on_navigation;
+pub use super::url_match::UrlMatch;
 use seed::virtual_dom::IntoNodes;
 use seed::{prelude::*, *};
 use std::borrow::Cow;
 
 use std::rc::Rc;
+use uuid::Uuid;
 use web_sys::MouseEvent;
 
+// ------ ------
+//     Init
+// ------ ------
+
+pub fn init(orders: &mut impl Orders<Msg>) -> Model {
+    Model {
+        expanded: false,
+        _url_changed_sub: orders.subscribe_with_handle(|_: subs::UrlChanged| Msg::Collapse),
+        id: Uuid::new_v4().to_string(),
+    }
+}
+
+// ------ ------
+//     Model
+// ------ ------
+
+pub struct Model {
+    expanded: bool,
+    _url_changed_sub: SubHandle,
+    id: String,
+}
+
+impl Model {
+    pub fn is_expanded(&self) -> bool {
+        self.expanded
+    }
+}
+
+// ------ ------
+//    Update
+// ------ ------
+
+#[derive(Debug)]
+pub enum Msg {
+    ToggleClicked,
+    Collapse,
+}
+
+pub fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {
+    match msg {
+        Msg::ToggleClicked => model.expanded = !model.expanded,
+        Msg::Collapse => {
+            if !model.expanded {
+                orders.skip();
+            }
+            model.expanded = false;
+        }
+    }
+}
+
 // ------ NavBar ------
 
 pub struct NavBar<Ms: 'static> {
@@ -14,7 +68,11 @@ pub struct NavBar<Ms: 'static> {
     toggle: Button<Ms>,
     attrs: Attrs,
     style: Style,
-    fixed_top: bool,
+    expand: Expand,
+    scheme: Option<Scheme>,
+    background: Option<Background>,
+    placement: Placement,
+    priority_plus: bool,
 }
 
 impl<Ms> NavBar<Ms> {
@@ -23,7 +81,6 @@ impl<Ms> NavBar<Ms> {
             .no_style()
             .add_attrs(C!["navbar-toggler"])
             .add_attrs(attrs! {
-                At::from("aria-expanded") => "false",
                 At::from("aria-label") => "Toggle navigation",
             })
             .content(span![C!["navbar-toggler-icon"]]);
@@ -34,12 +91,56 @@ impl<Ms> NavBar<Ms> {
             toggle,
             attrs: Attrs::empty(),
             style: Style::empty(),
-            fixed_top: true,
+            expand: Expand::Never,
+            scheme: None,
+            background: None,
+            placement: Placement::FixedTop,
+            priority_plus: false,
         }
     }
 
     pub fn fixed_top(mut self, fixed_top: bool) -> Self {
-        self.fixed_top = fixed_top;
+        self.placement = if fixed_top {
+            Placement::FixedTop
+        } else {
+            Placement::Static
+        };
+        self
+    }
+
+    pub fn expand(mut self, expand: Expand) -> Self {
+        self.expand = expand;
+        self
+    }
+
+    pub fn scheme(mut self, scheme: Scheme) -> Self {
+        self.scheme = Some(scheme);
+        self
+    }
+
+    pub fn light(self) -> Self {
+        self.scheme(Scheme::Light)
+    }
+
+    pub fn dark(self) -> Self {
+        self.scheme(Scheme::Dark)
+    }
+
+    pub fn background(mut self, background: Background) -> Self {
+        self.background = Some(background);
+        self
+    }
+
+    pub fn placement(mut self, placement: Placement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    // The navbar never collapses and its content is allowed to shrink,
+    // so a `priority_nav::PriorityNav` can move the links that don't fit into its "More" dropdown.
+    pub fn priority_plus(mut self) -> Self {
+        self.expand = Expand::Always;
+        self.priority_plus = true;
         self
     }
 
@@ -68,9 +169,18 @@ impl<Ms> NavBar<Ms> {
         self
     }
 
+    fn classes(&self) -> Vec<&'static str> {
+        let mut classes = vec!["navbar"];
+        classes.extend(self.expand.as_class());
+        classes.extend(self.scheme.map(Scheme::as_class));
+        classes.extend(self.background.map(Background::as_class));
+        classes.extend(self.placement.as_class());
+        classes
+    }
+
     pub fn view(self) -> Node<Ms> {
         nav![
-            C!["navbar", IF!(self.fixed_top => "fixed-top")],
+            C![self.classes()],
             self.style,
             self.attrs,
             self.brand,
@@ -83,19 +193,54 @@ impl<Ms> NavBar<Ms> {
         expanded: bool,
         content_id: impl Into<Cow<'static, str>>,
     ) -> Node<Ms> {
-        let content_id = content_id.into();
+        self.view_collapse(expanded, content_id.into(), None)
+    }
+
+    // The navbar collapses on its own when a nav link or a dropdown item is clicked.
+    pub fn view_with_model(
+        self,
+        model: &Model,
+        to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
+    ) -> Node<Ms> {
+        let toggle_to_msg = to_msg.clone();
+        self.update_toggle(|toggle| toggle.add_on_click(|_| toggle_to_msg(Msg::ToggleClicked)))
+            .view_collapse(
+                model.expanded,
+                Cow::Owned(model.id.clone()),
+                Some(on_navigation(move || to_msg(Msg::Collapse))),
+            )
+    }
+
+    fn view_collapse(
+        self,
+        expanded: bool,
+        content_id: Cow<'static, str>,
+        on_content_click: Option<EventHandler<Ms>>,
+    ) -> Node<Ms> {
+        let classes = self.classes();
+        // A navbar that is always expanded has nothing to toggle.
+        let toggle = if self.expand.collapses() {
+            self.toggle
+                .add_attrs(attrs! {
+                    At::from("aria-controls") => content_id,
+                    At::from("aria-expanded") => expanded,
+                })
+                .view()
+        } else {
+            empty![]
+        };
         nav![
-            C!["navbar", IF!(self.fixed_top => "fixed-top")],
+            C![classes],
             self.style,
             self.attrs,
             self.brand,
             vec![
-                self.toggle
-                    .add_attrs(attrs! { At::from("aria-controls") => content_id })
-                    .view(),
+                toggle,
                 div![
                     C!["collapse", "navbar-collapse", IF!(expanded => "show")],
                     id!(content_id),
+                    IF!(self.priority_plus => style! {St::MinWidth => 0}),
+                    on_content_click,
                     self.content,
                 ]
             ]
@@ -103,6 +248,106 @@ impl<Ms> NavBar<Ms> {
     }
 }
 
+// ------ Expand ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Expand {
+    Sm,
+    Md,
+    Lg,
+    Xl,
+    Always,
+    Never,
+}
+
+impl Expand {
+    fn as_class(self) -> Option<&'static str> {
+        match self {
+            Self::Sm => Some("navbar-expand-sm"),
+            Self::Md => Some("navbar-expand-md"),
+            Self::Lg => Some("navbar-expand-lg"),
+            Self::Xl => Some("navbar-expand-xl"),
+            Self::Always => Some("navbar-expand"),
+            Self::Never => None,
+        }
+    }
+
+    fn collapses(self) -> bool {
+        self != Self::Always
+    }
+}
+
+// ------ Scheme ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scheme {
+    Light,
+    Dark,
+}
+
+impl Scheme {
+    fn as_class(self) -> &'static str {
+        match self {
+            Self::Light => "navbar-light",
+            Self::Dark => "navbar-dark",
+        }
+    }
+}
+
+// ------ Background ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Background {
+    Primary,
+    Secondary,
+    Success,
+    Danger,
+    Warning,
+    Info,
+    Light,
+    Dark,
+    White,
+    Transparent,
+}
+
+impl Background {
+    fn as_class(self) -> &'static str {
+        match self {
+            Self::Primary => "bg-primary",
+            Self::Secondary => "bg-secondary",
+            Self::Success => "bg-success",
+            Self::Danger => "bg-danger",
+            Self::Warning => "bg-warning",
+            Self::Info => "bg-info",
+            Self::Light => "bg-light",
+            Self::Dark => "bg-dark",
+            Self::White => "bg-white",
+            Self::Transparent => "bg-transparent",
+        }
+    }
+}
+
+// ------ Placement ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Placement {
+    Static,
+    FixedTop,
+    FixedBottom,
+    StickyTop,
+}
+
+impl Placement {
+    fn as_class(self) -> Option<&'static str> {
+        match self {
+            Self::Static => None,
+            Self::FixedTop => Some("fixed-top"),
+            Self::FixedBottom => Some("fixed-bottom"),
+            Self::StickyTop => Some("sticky-top"),
+        }
+    }
+}
+
 // ------ Brand ------
 
 pub struct Brand<Ms: 'static> {
@@ -182,6 +427,11 @@ impl<Ms> Nav<Ms> {
         self
     }
 
+    // Pushes this and the following navbar content to the right.
+    pub fn align_right(self) -> Self {
+        self.add_attrs(C!["ml-auto"])
+    }
+
     pub fn view(self) -> Node<Ms> {
         ul![C!["navbar-nav"], self.style, self.attrs, self.content,]
     }
@@ -197,6 +447,145 @@ impl<Ms> Default for Nav<Ms> {
     }
 }
 
+// ------ NavText ------
+
+pub struct NavText<Ms: 'static> {
+    content: Vec<Node<Ms>>,
+    attrs: Attrs,
+    style: Style,
+}
+
+impl<Ms> UpdateEl<Ms> for NavText<Ms> {
+    fn update_el(self, el: &mut El<Ms>) {
+        self.view().update_el(el)
+    }
+}
+
+impl<Ms> NavText<Ms> {
+    pub fn new(content: impl IntoNodes<Ms>) -> Self {
+        Self {
+            content: content.into_nodes(),
+            attrs: Attrs::empty(),
+            style: Style::empty(),
+        }
+    }
+
+    pub fn add_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs.merge(attrs);
+        self
+    }
+
+    pub fn add_style(mut self, style: Style) -> Self {
+        self.style.merge(style);
+        self
+    }
+
+    pub fn view(self) -> Node<Ms> {
+        span![C!["navbar-text"], self.style, self.attrs, self.content]
+    }
+}
+
+// ------ SearchForm ------
+
+pub struct SearchForm<Ms: 'static> {
+    id: Cow<'static, str>,
+    value: Cow<'static, str>,
+    placeholder: Cow<'static, str>,
+    on_input: Option<Rc<dyn Fn(String) -> Ms>>,
+    on_submit: Option<Rc<dyn Fn() -> Ms>>,
+    button: Button<Ms>,
+    attrs: Attrs,
+    style: Style,
+}
+
+impl<Ms> UpdateEl<Ms> for SearchForm<Ms> {
+    fn update_el(self, el: &mut El<Ms>) {
+        self.view().update_el(el)
+    }
+}
+
+impl<Ms> SearchForm<Ms> {
+    pub fn new(id: impl Into<Cow<'static, str>>) -> Self {
+        let button = Button::new("Search")
+            .outline()
+            .success()
+            .button(button::Type::Submit)
+            .add_attrs(C!["my-2", "my-sm-0"]);
+
+        Self {
+            id: id.into(),
+            value: Cow::Borrowed(""),
+            placeholder: Cow::Borrowed("Search"),
+            on_input: None,
+            on_submit: None,
+            button,
+            attrs: Attrs::empty(),
+            style: Style::empty(),
+        }
+    }
+
+    pub fn value(mut self, value: impl Into<Cow<'static, str>>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<Cow<'static, str>>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    pub fn on_input(mut self, on_input: impl Fn(String) -> Ms + Clone + 'static) -> Self {
+        self.on_input = Some(Rc::new(on_input));
+        self
+    }
+
+    pub fn on_submit(mut self, on_submit: impl FnOnce() -> Ms + Clone + 'static) -> Self {
+        self.on_submit = Some(Rc::new(move || on_submit.clone()()));
+        self
+    }
+
+    pub fn update_button(mut self, f: impl FnOnce(Button<Ms>) -> Button<Ms>) -> Self {
+        self.button = f(self.button);
+        self
+    }
+
+    pub fn add_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs.merge(attrs);
+        self
+    }
+
+    pub fn add_style(mut self, style: Style) -> Self {
+        self.style.merge(style);
+        self
+    }
+
+    pub fn view(self) -> Node<Ms> {
+        let mut input = FormGroup::new(self.id)
+            .value(self.value)
+            .group_attrs(C!["mb-0", "mr-sm-2"])
+            .input_attrs(attrs! {
+                At::Placeholder => self.placeholder,
+                At::from("aria-label") => self.placeholder,
+            });
+        if let Some(on_input) = self.on_input {
+            input = input.on_input(move |value| on_input(value));
+        }
+        let on_submit = self.on_submit;
+        form![
+            C!["form-inline", "my-2", "my-lg-0"],
+            self.style,
+            self.attrs,
+            ev(Ev::Submit, move |event| {
+                // The search is handled by the app, not by the browser.
+                event.prevent_default();
+                on_submit.map(|on_submit| on_submit())
+            }),
+            input,
+            self.button,
+        ]
+    }
+}
+
 // ------ NavLink ------
 
 pub struct NavLink<Ms: 'static> {
@@ -205,6 +594,7 @@ pub struct NavLink<Ms: 'static> {
     active: bool,
     disabled: bool,
     icon: Option<Node<Ms>>,
+    icon_only: bool,
     attrs: Attrs,
     inner_attrs: Attrs,
     style: Style,
@@ -225,6 +615,7 @@ impl<Ms> NavLink<Ms> {
             active: false,
             disabled: false,
             icon: None,
+            icon_only: false,
             attrs: Attrs::empty(),
             inner_attrs: Attrs::empty(),
             style: Style::empty(),
@@ -237,6 +628,11 @@ impl<Ms> NavLink<Ms> {
         self
     }
 
+    pub fn active_for_url(self, url: &Url, url_match: UrlMatch) -> Self {
+        let active = url_match.matches(&self.link, url);
+        self.active(active)
+    }
+
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
         self
@@ -247,6 +643,12 @@ impl<Ms> NavLink<Ms> {
         self
     }
 
+    // The title is still read by screen readers and shown as a tooltip.
+    pub fn icon_only(mut self, icon_only: bool) -> Self {
+        self.icon_only = icon_only;
+        self
+    }
+
     pub fn add_attrs(mut self, attrs: Attrs) -> Self {
         self.attrs.merge(attrs);
         self
@@ -271,6 +673,35 @@ impl<Ms> NavLink<Ms> {
         self
     }
 
+    pub(crate) fn is_active(&self) -> bool {
+        self.active
+    }
+
+    // The same link as a `.dropdown-item`, e.g. in the "More" dropdown of a `PriorityNav`.
+    pub(crate) fn view_as_dropdown_item(&self) -> Node<Ms> {
+        let mut elem = a![
+            C![
+                "dropdown-item",
+                IF!(self.disabled => "disabled"),
+                IF!(self.active => "active")
+            ],
+            attrs! {
+                At::Href => self.link
+                At::TabIndex => if self.disabled { AtValue::Some((-1).to_string()) } else { AtValue::Ignored },
+                At::from("aria-disabled") => if self.disabled { AtValue::Some(true.to_string()) } else { AtValue::Ignored },
+                At::from("aria-current") => if self.active { AtValue::Some("page".to_owned()) } else { AtValue::Ignored },
+            },
+            &self.inner_attrs,
+            self.icon.clone(),
+            &self.title,
+        ];
+        for on_click in &self.on_clicks {
+            let on_click = Rc::clone(on_click);
+            elem.add_event_handler(mouse_ev(Ev::Click, move |event| on_click(event)));
+        }
+        elem
+    }
+
     pub fn view(self) -> Node<Ms> {
         let mut elem = li![
             C!["nav-item", IF!(self.active => "active")],
@@ -286,6 +717,8 @@ impl<Ms> NavLink<Ms> {
                     At::Href => self.link
                     At::TabIndex => if self.disabled { AtValue::Some((-1).to_string()) } else { AtValue::Ignored },
                     At::from("aria-disabled") => if self.disabled { AtValue::Some(true.to_string()) } else { AtValue::Ignored },
+                    At::from("aria-current") => if self.active { AtValue::Some("page".to_owned()) } else { AtValue::Ignored },
+                    At::Title => if self.icon_only { AtValue::Some(self.title.to_string()) } else { AtValue::Ignored },
                 },
                 self.inner_attrs,
                 if let Some(icon) = self.icon {
@@ -293,11 +726,10 @@ impl<Ms> NavLink<Ms> {
                 } else {
                     empty![]
                 },
-                self.title,
-                if self.active {
-                    span![C!["sr-only"], " (current)"]
+                if self.icon_only {
+                    span![C!["sr-only"], self.title]
                 } else {
-                    empty![]
+                    plain![self.title]
                 },
             ],
         ];
@@ -0,0 +1,22 @@
+use seed::{prelude::*, *};
+use wasm_bindgen::JsCast;
+use web_sys::Element;
+
+// Clicks on these elements navigate away, so the menu that contains them is closed.
+// Submenu triggers, multi-select items and selection actions keep the menu in use.
+const NAVIGATION_SELECTOR: &str = "a.nav-link:not(.dropdown-toggle), \
+    a.dropdown-item:not(.dropdown-toggle):not([role=menuitemcheckbox])";
+
+// A click handler for a container that sends the message when a navigating link in it is clicked.
+pub(crate) fn on_navigation<Ms: 'static, MsU: 'static>(
+    handler: impl FnOnce() -> MsU + Clone + 'static,
+) -> EventHandler<Ms> {
+    ev(Ev::Click, move |event| {
+        let is_navigation = event
+            .target()
+            .and_then(|target| target.dyn_into::<Element>().ok())
+            .and_then(|target| target.closest(NAVIGATION_SELECTOR).ok().flatten())
+            .is_some();
+        IF!(is_navigation => handler())
+    })
+}
@@ -0,0 +1,452 @@
+pub use super::breakpoint::Breakpoint;
+use super::dismiss::{self, DismissHandle};
+use super::navbar::NavLink;
+use super::navigation::on_navigation;
+use seed::virtual_dom::IntoNodes;
+use seed::{prelude::*, *};
+use std::borrow::Cow;
+use uuid::Uuid;
+use wasm_bindgen::JsCast;
+use web_sys::HtmlElement;
+
+// ------ ------
+//     Init
+// ------ ------
+
+pub fn init(orders: &mut impl Orders<Msg>) -> Model {
+    Model {
+        open: false,
+        mini: false,
+        overlay_below: None,
+        overlay: false,
+        open_sections: Vec::new(),
+        id: Uuid::new_v4().to_string(),
+        panel: ElRef::default(),
+        dismiss_handle: None,
+        scroll_lock: None,
+        opener: None,
+        _resize_stream: orders
+            .stream_with_handle(streams::window_event(Ev::Resize, |_| Msg::WindowResized)),
+    }
+}
+
+// ------ ------
+//     Model
+// ------ ------
+
+pub struct Model {
+    open: bool,
+    mini: bool,
+    overlay_below: Option<Breakpoint>,
+    overlay: bool,
+    open_sections: Vec<usize>,
+    id: String,
+    panel: ElRef<HtmlElement>,
+    dismiss_handle: Option<DismissHandle>,
+    scroll_lock: Option<ScrollLock>,
+    // The element focused before the drawer was opened gets the focus back when it's closed.
+    opener: Option<HtmlElement>,
+    _resize_stream: StreamHandle,
+}
+
+impl Model {
+    // The sidebar is docked on wider screens and becomes a drawer below the breakpoint.
+    // Without a breakpoint it's always a drawer.
+    pub fn overlay_below(mut self, breakpoint: Breakpoint) -> Self {
+        self.overlay_below = Some(breakpoint);
+        self.overlay = is_below(breakpoint);
+        self
+    }
+
+    pub fn mini(mut self, mini: bool) -> Self {
+        self.mini = mini;
+        self
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    pub fn is_mini(&self) -> bool {
+        self.mini
+    }
+
+    pub fn is_overlay(&self) -> bool {
+        self.overlay_below.is_none() || self.overlay
+    }
+}
+
+fn is_below(breakpoint: Breakpoint) -> bool {
+    let width = window().inner_width().ok().and_then(|width| width.as_f64());
+    matches!(width, Some(width) if width < f64::from(breakpoint.min_width()))
+}
+
+// ------ ScrollLock ------
+
+// Prevents the page under the drawer from scrolling until it's dropped.
+struct ScrollLock {
+    previous_overflow: String,
+}
+
+impl ScrollLock {
+    fn new() -> Self {
+        let style = body().style();
+        let previous_overflow = style.get_property_value("overflow").unwrap_or_default();
+        let _ = style.set_property("overflow", "hidden");
+        Self { previous_overflow }
+    }
+}
+
+impl Drop for ScrollLock {
+    fn drop(&mut self) {
+        let _ = body()
+            .style()
+            .set_property("overflow", &self.previous_overflow);
+    }
+}
+
+// ------ ------
+//    Update
+// ------ ------
+
+#[derive(Debug)]
+pub enum Msg {
+    Open,
+    Close,
+    // Opens or closes the drawer, or switches the mini mode of the docked sidebar.
+    Toggle,
+    ToggleMini,
+    ToggleSection(usize),
+    WatchDismiss,
+    FocusPanel,
+    WindowResized,
+}
+
+pub fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {
+    match msg {
+        Msg::Open => open(model, orders),
+        Msg::Close => close(model),
+        Msg::Toggle => {
+            if !model.is_overlay() {
+                model.mini = !model.mini;
+            } else if model.open {
+                close(model);
+            } else {
+                open(model, orders);
+            }
+        }
+        Msg::ToggleMini => model.mini = !model.mini,
+        Msg::ToggleSection(index) => {
+            // Sections can't be expanded in the mini mode, so the sidebar is widened first.
+            if model.mini && !model.is_overlay() {
+                model.mini = false;
+                if !model.open_sections.contains(&index) {
+                    model.open_sections.push(index);
+                }
+            } else if let Some(position) = model.open_sections.iter().position(|i| *i == index) {
+                model.open_sections.remove(position);
+            } else {
+                model.open_sections.push(index);
+            }
+        }
+        Msg::WatchDismiss => {
+            if model.open && model.dismiss_handle.is_none() {
+                let (app, msg_mapper) = (orders.clone_app(), orders.msg_mapper());
+                model.dismiss_handle =
+                    Some(dismiss::on_dismiss(vec![model.panel.clone()], move || {
+                        app.update(msg_mapper(Msg::Close))
+                    }));
+            }
+        }
+        Msg::FocusPanel => {
+            if let Some(panel) = model.panel.get() {
+                let _ = panel.focus();
+            }
+        }
+        Msg::WindowResized => {
+            let overlay = matches!(model.overlay_below, Some(breakpoint) if is_below(breakpoint));
+            if overlay == model.overlay {
+                orders.skip();
+                return;
+            }
+            model.overlay = overlay;
+            if !overlay {
+                close(model);
+            }
+        }
+    }
+}
+
+fn open(model: &mut Model, orders: &mut impl Orders<Msg>) {
+    if !model.is_overlay() || model.open {
+        return;
+    }
+    model.open = true;
+    model.scroll_lock = Some(ScrollLock::new());
+    model.opener = document()
+        .active_element()
+        .and_then(|element| element.dyn_into::<HtmlElement>().ok());
+    // The click that opened the drawer must not be handled as a click outside of it.
+    orders.after_next_render(|_| Msg::WatchDismiss);
+    orders.after_next_render(|_| Msg::FocusPanel);
+}
+
+fn close(model: &mut Model) {
+    model.open = false;
+    model.dismiss_handle = None;
+    model.scroll_lock = None;
+    if let Some(opener) = model.opener.take() {
+        if opener.is_connected() {
+            let _ = opener.focus();
+        }
+    }
+}
+
+// ------ ------
+//     View
+// ------ ------
+
+// ------ Offcanvas ------
+
+pub struct Offcanvas<Ms: 'static> {
+    side: Side,
+    backdrop: bool,
+    header: Vec<Node<Ms>>,
+    items: Vec<Item<Ms>>,
+    width: Cow<'static, str>,
+    mini_width: Cow<'static, str>,
+    attrs: Attrs,
+    style: Style,
+}
+
+impl<Ms> Default for Offcanvas<Ms> {
+    fn default() -> Self {
+        Self {
+            side: Side::Left,
+            backdrop: true,
+            header: Vec::new(),
+            items: Vec::new(),
+            width: "16rem".into(),
+            mini_width: "4.5rem".into(),
+            attrs: Attrs::empty(),
+            style: Style::empty(),
+        }
+    }
+}
+
+impl<Ms> Offcanvas<Ms> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn side(mut self, side: Side) -> Self {
+        self.side = side;
+        self
+    }
+
+    pub fn backdrop(mut self, backdrop: bool) -> Self {
+        self.backdrop = backdrop;
+        self
+    }
+
+    pub fn header(mut self, header: impl IntoNodes<Ms>) -> Self {
+        self.header = header.into_nodes();
+        self
+    }
+
+    pub fn items(mut self, items: Vec<Item<Ms>>) -> Self {
+        self.items = items;
+        self
+    }
+
+    pub fn width(mut self, width: impl Into<Cow<'static, str>>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    pub fn mini_width(mut self, mini_width: impl Into<Cow<'static, str>>) -> Self {
+        self.mini_width = mini_width.into();
+        self
+    }
+
+    pub fn add_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs.merge(attrs);
+        self
+    }
+
+    pub fn add_style(mut self, style: Style) -> Self {
+        self.style.merge(style);
+        self
+    }
+
+    pub fn view(self, model: &Model, to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static) -> Node<Ms> {
+        if model.is_overlay() {
+            self.view_drawer(model, to_msg)
+        } else {
+            self.view_docked(model, to_msg)
+        }
+    }
+
+    fn view_docked(
+        self,
+        model: &Model,
+        to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
+    ) -> Node<Ms> {
+        let mini = model.mini;
+        let border = match self.side {
+            Side::Left => "border-right",
+            Side::Right => "border-left",
+        };
+        nav![
+            C![
+                "offcanvas",
+                "bg-light",
+                border,
+                "flex-shrink-0",
+                IF!(mini => "offcanvas-mini")
+            ],
+            style! {
+                St::Width => if mini { &self.mini_width } else { &self.width },
+                St::OverflowY => "auto",
+                St::Transition => "width 0.2s ease-in-out",
+            },
+            self.style,
+            self.attrs,
+            el_ref(&model.panel),
+            IF!(!mini => self.header),
+            view_items(self.items, model, mini, &to_msg),
+        ]
+    }
+
+    fn view_drawer(
+        self,
+        model: &Model,
+        to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
+    ) -> Node<Ms> {
+        let open = model.open;
+        let (edge, hidden_transform) = match self.side {
+            Side::Left => (St::Left, "translateX(-100%)"),
+            Side::Right => (St::Right, "translateX(100%)"),
+        };
+        let close_on_navigation = {
+            let to_msg = to_msg.clone();
+            on_navigation(move || to_msg(Msg::Close))
+        };
+        div![
+            IF!(self.backdrop && open => div![C!["modal-backdrop", "fade", "show"]]),
+            nav![
+                C!["offcanvas", "bg-white", "shadow"],
+                style! {
+                    St::Position => "fixed",
+                    St::Top => 0,
+                    St::Bottom => 0,
+                    edge => 0,
+                    St::ZIndex => 1050,
+                    St::Width => &self.width,
+                    St::MaxWidth => "100%",
+                    St::OverflowY => "auto",
+                    St::Outline => "none",
+                    St::Transform => if open { "none" } else { hidden_transform },
+                    St::Visibility => if open { "visible" } else { "hidden" },
+                    St::Transition => "transform 0.3s ease-in-out, visibility 0.3s",
+                },
+                attrs! {
+                    At::from("role") => "dialog",
+                    At::TabIndex => -1,
+                    At::from("aria-modal") => if open { AtValue::Some(true.to_string()) } else { AtValue::Ignored },
+                    At::from("aria-hidden") => if open { AtValue::Ignored } else { AtValue::Some(true.to_string()) },
+                },
+                self.style,
+                self.attrs,
+                el_ref(&model.panel),
+                close_on_navigation,
+                self.header,
+                view_items(self.items, model, false, &to_msg),
+            ]
+        ]
+    }
+}
+
+fn view_items<Ms: 'static>(
+    items: Vec<Item<Ms>>,
+    model: &Model,
+    mini: bool,
+    to_msg: &(impl FnOnce(Msg) -> Ms + Clone + 'static),
+) -> Node<Ms> {
+    ul![
+        C!["nav", "flex-column", "py-2"],
+        items.into_iter().enumerate().map(|(index, item)| match item {
+            Item::Link(link) => (*link).icon_only(mini).view(),
+            Item::Section { title, icon, links } => {
+                let open = !mini && model.open_sections.contains(&index);
+                let content_id = format!("{}-section-{}", model.id, index);
+                let to_msg = to_msg.clone();
+                li![
+                    C!["nav-item"],
+                    button![
+                        C!["nav-link", "btn", "btn-link", "w-100", "text-left", "text-reset"],
+                        attrs! {
+                            At::Type => "button",
+                            At::Title => if mini { AtValue::Some(title.to_string()) } else { AtValue::Ignored },
+                            At::from("aria-expanded") => open,
+                            At::from("aria-controls") => content_id,
+                        },
+                        icon.map(|icon| *icon),
+                        if mini {
+                            span![C!["sr-only"], title]
+                        } else {
+                            span![C!["dropdown-toggle"], title]
+                        },
+                        ev(Ev::Click, move |_| to_msg(Msg::ToggleSection(index))),
+                    ],
+                    ul![
+                        C!["nav", "flex-column", "pl-3", "collapse", IF!(open => "show")],
+                        id!(content_id),
+                        links.into_iter().map(NavLink::view),
+                    ],
+                ]
+            }
+        })
+    ]
+}
+
+// ------ Side ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+// ------ Item ------
+
+pub enum Item<Ms: 'static> {
+    Link(Box<NavLink<Ms>>),
+    Section {
+        title: Cow<'static, str>,
+        icon: Option<Box<Node<Ms>>>,
+        links: Vec<NavLink<Ms>>,
+    },
+}
+
+impl<Ms> Item<Ms> {
+    pub fn link(link: NavLink<Ms>) -> Self {
+        Self::Link(Box::new(link))
+    }
+
+    pub fn section(title: impl Into<Cow<'static, str>>, links: Vec<NavLink<Ms>>) -> Self {
+        Self::Section {
+            title: title.into(),
+            icon: None,
+            links,
+        }
+    }
+
+    // Shown in the mini mode, links need their own icons set by `NavLink::icon`.
+    pub fn icon(mut self, new_icon: Node<Ms>) -> Self {
+        if let Self::Section { icon, .. } = &mut self {
+            *icon = Some(Box::new(new_icon));
+        }
+        self
+    }
+}
@@ -0,0 +1,312 @@
+#[cfg(not(feature = "popper"))]
+use super::position::Positioner;
+use seed::prelude::*;
+#[cfg(feature = "popper")]
+use std::sync::atomic::{AtomicBool, Ordering};
+use web_sys::HtmlElement;
+
+#[cfg(feature = "popper")]
+type PopperInstance = JsValue;
+
+// ------ PopperOptions ------
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PopperOptions {
+    pub(crate) placement: Placement,
+    pub(crate) strategy: Strategy,
+    pub(crate) offset: (f64, f64),
+    pub(crate) flip: bool,
+    pub(crate) prevent_overflow: bool,
+    pub(crate) boundary: Boundary,
+    pub(crate) static_display: bool,
+}
+
+impl Default for PopperOptions {
+    fn default() -> Self {
+        Self {
+            placement: Placement::BottomStart,
+            strategy: Strategy::Fixed,
+            offset: (0., 2.),
+            flip: true,
+            prevent_overflow: true,
+            boundary: Boundary::ClippingParents,
+            static_display: false,
+        }
+    }
+}
+
+// ------ Placement ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Placement {
+    Auto,
+    AutoStart,
+    AutoEnd,
+    Top,
+    TopStart,
+    TopEnd,
+    Bottom,
+    BottomStart,
+    BottomEnd,
+    Right,
+    RightStart,
+    RightEnd,
+    Left,
+    LeftStart,
+    LeftEnd,
+}
+
+#[cfg(feature = "popper")]
+impl Placement {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::AutoStart => "auto-start",
+            Self::AutoEnd => "auto-end",
+            Self::Top => "top",
+            Self::TopStart => "top-start",
+            Self::TopEnd => "top-end",
+            Self::Bottom => "bottom",
+            Self::BottomStart => "bottom-start",
+            Self::BottomEnd => "bottom-end",
+            Self::Right => "right",
+            Self::RightStart => "right-start",
+            Self::RightEnd => "right-end",
+            Self::Left => "left",
+            Self::LeftStart => "left-start",
+            Self::LeftEnd => "left-end",
+        }
+    }
+}
+
+// ------ Strategy ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    Absolute,
+    Fixed,
+}
+
+#[cfg(feature = "popper")]
+impl Strategy {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Absolute => "absolute",
+            Self::Fixed => "fixed",
+        }
+    }
+}
+
+// ------ Boundary ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Boundary {
+    ClippingParents,
+    Viewport,
+    Document,
+}
+
+#[cfg(feature = "popper")]
+impl Boundary {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::ClippingParents => "clippingParents",
+            Self::Viewport => "viewport",
+            Self::Document => "document",
+        }
+    }
+}
+
+// ------ PopperError ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PopperError {
+    ReferenceNotMounted,
+    PopupNotMounted,
+    #[cfg(feature = "popper")]
+    NotLoaded,
+}
+
+impl PopperError {
+    // The popup keeps its Bootstrap CSS position in all cases.
+    // A missing Popper is reported only once by `JsPopper::check_loaded`.
+    pub(crate) fn log(self) {
+        match self {
+            Self::ReferenceNotMounted => {
+                seed::error!("Popup reference is not mounted - falling back to CSS positioning.")
+            }
+            Self::PopupNotMounted => {
+                seed::error!("Popup is not mounted - falling back to CSS positioning.")
+            }
+            #[cfg(feature = "popper")]
+            Self::NotLoaded => {}
+        }
+    }
+}
+
+// ------ PopperData ------
+
+pub(crate) struct PopperData {
+    options: PopperOptions,
+    #[cfg(feature = "popper")]
+    popper: JsPopper,
+    #[cfg(not(feature = "popper"))]
+    positioner: Positioner,
+}
+
+impl PopperData {
+    pub(crate) fn new(
+        reference: &ElRef<HtmlElement>,
+        popup: &ElRef<HtmlElement>,
+        options: PopperOptions,
+        on_apply_styles: impl FnMut(String) + 'static,
+    ) -> Result<Self, PopperError> {
+        #[cfg(feature = "popper")]
+        JsPopper::check_loaded()?;
+        let reference = reference.get().ok_or(PopperError::ReferenceNotMounted)?;
+        let popup = popup.get().ok_or(PopperError::PopupNotMounted)?;
+        Ok(Self {
+            #[cfg(feature = "popper")]
+            popper: JsPopper::new(reference, popup, &options, on_apply_styles),
+            #[cfg(not(feature = "popper"))]
+            positioner: Positioner::new(reference, popup, options.clone(), on_apply_styles),
+            options,
+        })
+    }
+
+    // Creates the popper of a popup that has just been opened. Without Popper or mounted refs
+    // the popup keeps its Bootstrap CSS position. The refs may be mounted only by the next render,
+    // so errors are logged by `update_or_show`.
+    pub(crate) fn show<Ms: 'static>(
+        popper_data: &mut Option<Self>,
+        reference: &ElRef<HtmlElement>,
+        popup: &ElRef<HtmlElement>,
+        options: PopperOptions,
+        orders: &mut impl Orders<Ms>,
+        on_apply_styles: impl Fn(String) -> Ms + 'static,
+    ) -> Result<(), PopperError> {
+        let (app, msg_mapper) = (orders.clone_app(), orders.msg_mapper());
+        *popper_data = Some(Self::new(reference, popup, options, move |popup_style| {
+            app.update(msg_mapper(on_apply_styles(popup_style)))
+        })?);
+        Ok(())
+    }
+
+    // Updates the popper after a render of the open popup,
+    // or creates it if the refs weren't mounted when the popup was opened.
+    pub(crate) fn update_or_show<Ms: 'static>(
+        popper_data: &mut Option<Self>,
+        reference: &ElRef<HtmlElement>,
+        popup: &ElRef<HtmlElement>,
+        options: PopperOptions,
+        orders: &mut impl Orders<Ms>,
+        on_apply_styles: impl Fn(String) -> Ms + 'static,
+    ) {
+        match popper_data {
+            Some(popper_data) => popper_data.update(),
+            None => {
+                if let Err(error) = Self::show(
+                    popper_data,
+                    reference,
+                    popup,
+                    options,
+                    orders,
+                    on_apply_styles,
+                ) {
+                    error.log();
+                }
+            }
+        }
+    }
+
+    pub(crate) fn options(&self) -> &PopperOptions {
+        &self.options
+    }
+
+    pub(crate) fn update(&self) {
+        #[cfg(feature = "popper")]
+        update_popper(&self.popper.popper_instance);
+        #[cfg(not(feature = "popper"))]
+        self.positioner.update();
+    }
+}
+
+// ------ JsPopper ------
+
+#[cfg(feature = "popper")]
+static NOT_LOADED_REPORTED: AtomicBool = AtomicBool::new(false);
+
+#[cfg(feature = "popper")]
+struct JsPopper {
+    popper_instance: PopperInstance,
+    _on_apply_styles: Closure<dyn FnMut(String)>,
+}
+
+#[cfg(feature = "popper")]
+impl JsPopper {
+    fn check_loaded() -> Result<(), PopperError> {
+        if is_popper_loaded() {
+            return Ok(());
+        }
+        // Bootstrap CSS still positions the popup, just without flipping and overflow checks.
+        if !NOT_LOADED_REPORTED.swap(true, Ordering::Relaxed) {
+            seed::error!("Popper is not loaded - falling back to CSS positioning of popups.");
+        }
+        Err(PopperError::NotLoaded)
+    }
+
+    fn new(
+        reference: HtmlElement,
+        popup: HtmlElement,
+        options: &PopperOptions,
+        on_apply_styles: impl FnMut(String) + 'static,
+    ) -> Self {
+        let closure = Closure::wrap(Box::new(on_apply_styles) as Box<dyn FnMut(String)>);
+        let closure_as_js_value = closure.as_ref().clone();
+
+        let popper_instance = create_popper(
+            reference,
+            popup,
+            options.placement.as_str(),
+            options.strategy.as_str(),
+            options.offset.0,
+            options.offset.1,
+            options.flip,
+            options.prevent_overflow,
+            options.boundary.as_str(),
+            closure_as_js_value,
+        );
+        Self {
+            popper_instance,
+            _on_apply_styles: closure,
+        }
+    }
+}
+
+#[cfg(feature = "popper")]
+impl Drop for JsPopper {
+    fn drop(&mut self) {
+        destroy_popper(&self.popper_instance)
+    }
+}
+
+#[cfg(feature = "popper")]
+#[wasm_bindgen(module = "/js/popper_wrapper.js")]
+extern "C" {
+    fn create_popper(
+        toggle_element: HtmlElement,
+        popup_element: HtmlElement,
+        placement: &str,
+        strategy: &str,
+        offset_skidding: f64,
+        offset_distance: f64,
+        flip: bool,
+        prevent_overflow: bool,
+        boundary: &str,
+        _on_apply_styles: JsValue,
+    ) -> PopperInstance;
+
+    fn is_popper_loaded() -> bool;
+    fn update_popper(popper_instance: &PopperInstance);
+    fn destroy_popper(popper_instance: &PopperInstance);
+}
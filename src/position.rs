@@ -0,0 +1,258 @@
+use super::popper::{Placement, PopperOptions, Strategy};
+use seed::{prelude::*, *};
+use std::{
+    cell::{Cell, RefCell},
+    rc::Rc,
+};
+use web_sys::{DomRect, HtmlElement};
+
+// ------ Positioner ------
+
+// Positions the popup next to the reference element without Popper.
+// Boundaries are approximated by the viewport.
+pub(crate) struct Positioner {
+    inner: Rc<Inner>,
+    on_viewport_change: Closure<dyn FnMut()>,
+}
+
+struct Inner {
+    reference: HtmlElement,
+    popup: HtmlElement,
+    options: PopperOptions,
+    on_apply_styles: RefCell<Box<dyn FnMut(String)>>,
+    active: Cell<bool>,
+}
+
+impl Positioner {
+    pub(crate) fn new(
+        reference: HtmlElement,
+        popup: HtmlElement,
+        options: PopperOptions,
+        on_apply_styles: impl FnMut(String) + 'static,
+    ) -> Self {
+        let inner = Rc::new(Inner {
+            reference,
+            popup,
+            options,
+            on_apply_styles: RefCell::new(Box::new(on_apply_styles)),
+            active: Cell::new(true),
+        });
+        let on_viewport_change = Closure::wrap(Box::new({
+            let inner = Rc::clone(&inner);
+            move || inner.apply()
+        }) as Box<dyn FnMut()>);
+
+        let window = window();
+        let callback = on_viewport_change.as_ref().unchecked_ref();
+        // Capture scroll events of all scrollable ancestors, not only the window.
+        let _ = window.add_event_listener_with_callback_and_bool("scroll", callback, true);
+        let _ = window.add_event_listener_with_callback("resize", callback);
+
+        let positioner = Self {
+            inner,
+            on_viewport_change,
+        };
+        positioner.update();
+        positioner
+    }
+
+    pub(crate) fn update(&self) {
+        // Styles are applied asynchronously like in Popper,
+        // so the callback is never invoked while the app is being updated.
+        let inner = Rc::clone(&self.inner);
+        spawn_local(async move { inner.apply() });
+    }
+}
+
+impl Drop for Positioner {
+    fn drop(&mut self) {
+        self.inner.active.set(false);
+        let window = window();
+        let callback = self.on_viewport_change.as_ref().unchecked_ref();
+        let _ = window.remove_event_listener_with_callback_and_bool("scroll", callback, true);
+        let _ = window.remove_event_listener_with_callback("resize", callback);
+    }
+}
+
+impl Inner {
+    fn apply(&self) {
+        if self.active.get() {
+            let style = compute_style(&self.reference, &self.popup, &self.options);
+            (self.on_apply_styles.borrow_mut())(style);
+        }
+    }
+}
+
+// ------ Side & Alignment ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl Side {
+    fn opposite(self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+
+    fn is_vertical(self) -> bool {
+        matches!(self, Self::Top | Self::Bottom)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Alignment {
+    Start,
+    Center,
+    End,
+}
+
+fn side_and_alignment(placement: Placement, auto_side: Side) -> (Side, Alignment) {
+    match placement {
+        Placement::Auto => (auto_side, Alignment::Center),
+        Placement::AutoStart => (auto_side, Alignment::Start),
+        Placement::AutoEnd => (auto_side, Alignment::End),
+        Placement::Top => (Side::Top, Alignment::Center),
+        Placement::TopStart => (Side::Top, Alignment::Start),
+        Placement::TopEnd => (Side::Top, Alignment::End),
+        Placement::Bottom => (Side::Bottom, Alignment::Center),
+        Placement::BottomStart => (Side::Bottom, Alignment::Start),
+        Placement::BottomEnd => (Side::Bottom, Alignment::End),
+        Placement::Right => (Side::Right, Alignment::Center),
+        Placement::RightStart => (Side::Right, Alignment::Start),
+        Placement::RightEnd => (Side::Right, Alignment::End),
+        Placement::Left => (Side::Left, Alignment::Center),
+        Placement::LeftStart => (Side::Left, Alignment::Start),
+        Placement::LeftEnd => (Side::Left, Alignment::End),
+    }
+}
+
+// ------ Layout ------
+
+struct Layout {
+    reference: DomRect,
+    width: f64,
+    height: f64,
+    viewport_width: f64,
+    viewport_height: f64,
+    offset: (f64, f64),
+}
+
+impl Layout {
+    // Free space between the reference and the viewport edge.
+    fn space(&self, side: Side) -> f64 {
+        match side {
+            Side::Top => self.reference.top(),
+            Side::Bottom => self.viewport_height - self.reference.bottom(),
+            Side::Left => self.reference.left(),
+            Side::Right => self.viewport_width - self.reference.right(),
+        }
+    }
+
+    fn fits(&self, side: Side) -> bool {
+        let size = if side.is_vertical() {
+            self.height
+        } else {
+            self.width
+        };
+        self.space(side) >= size + self.offset.1
+    }
+
+    // Viewport coordinates of the popup's top left corner.
+    fn position(&self, side: Side, alignment: Alignment) -> (f64, f64) {
+        let reference = &self.reference;
+        let (skidding, distance) = self.offset;
+        let align = |start: f64, reference_size: f64, size: f64| {
+            skidding
+                + match alignment {
+                    Alignment::Start => start,
+                    Alignment::Center => start + (reference_size - size) / 2.,
+                    Alignment::End => start + reference_size - size,
+                }
+        };
+        match side {
+            Side::Top => (
+                align(reference.left(), reference.width(), self.width),
+                reference.top() - self.height - distance,
+            ),
+            Side::Bottom => (
+                align(reference.left(), reference.width(), self.width),
+                reference.bottom() + distance,
+            ),
+            Side::Left => (
+                reference.left() - self.width - distance,
+                align(reference.top(), reference.height(), self.height),
+            ),
+            Side::Right => (
+                reference.right() + distance,
+                align(reference.top(), reference.height(), self.height),
+            ),
+        }
+    }
+}
+
+fn compute_style(reference: &HtmlElement, popup: &HtmlElement, options: &PopperOptions) -> String {
+    let (viewport_width, viewport_height) = match document().document_element() {
+        Some(root) => (
+            f64::from(root.client_width()),
+            f64::from(root.client_height()),
+        ),
+        None => (0., 0.),
+    };
+    let layout = Layout {
+        reference: reference.get_bounding_client_rect(),
+        width: f64::from(popup.offset_width()),
+        height: f64::from(popup.offset_height()),
+        viewport_width,
+        viewport_height,
+        offset: options.offset,
+    };
+
+    let auto_side = [Side::Bottom, Side::Top, Side::Right, Side::Left]
+        .iter()
+        .copied()
+        .find(|side| layout.fits(*side))
+        .unwrap_or(Side::Bottom);
+    let (mut side, alignment) = side_and_alignment(options.placement, auto_side);
+    if options.flip && !layout.fits(side) && layout.fits(side.opposite()) {
+        side = side.opposite();
+    }
+
+    let (mut x, mut y) = layout.position(side, alignment);
+    if options.prevent_overflow {
+        if side.is_vertical() {
+            x = x.min(viewport_width - layout.width).max(0.);
+        } else {
+            y = y.min(viewport_height - layout.height).max(0.);
+        }
+    }
+
+    let (x, y) = match (options.strategy, popup.offset_parent()) {
+        (Strategy::Absolute, Some(parent)) => {
+            let parent_rect = parent.get_bounding_client_rect();
+            (
+                x - parent_rect.left() + f64::from(parent.scroll_left() - parent.client_left()),
+                y - parent_rect.top() + f64::from(parent.scroll_top() - parent.client_top()),
+            )
+        }
+        _ => (x, y),
+    };
+    let position = match options.strategy {
+        Strategy::Absolute => "absolute",
+        Strategy::Fixed => "fixed",
+    };
+    format!(
+        "position: {}; left: 0px; top: 0px; transform: translate({}px, {}px);",
+        position,
+        x.round(),
+        y.round(),
+    )
+}
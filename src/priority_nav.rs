@@ -0,0 +1,234 @@
+use super::dropdown::{self, Align, Dropdown, Item};
+use super::navbar::NavLink;
+use seed::{prelude::*, *};
+use std::borrow::Cow;
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlElement, ResizeObserver};
+
+const LINK_SELECTOR: &str = ":scope > [data-priority-link]";
+const MORE_SELECTOR: &str = ":scope > [data-priority-more]";
+
+// ------ ------
+//     Init
+// ------ ------
+
+pub fn init(orders: &mut impl Orders<Msg>) -> Model {
+    orders.after_next_render(|_| Msg::Measure);
+    Model {
+        nav: ElRef::default(),
+        visible: None,
+        more: dropdown::init(&mut orders.proxy(Msg::MoreMsg)),
+        observer: None,
+    }
+}
+
+// ------ ------
+//     Model
+// ------ ------
+
+pub struct Model {
+    nav: ElRef<HtmlElement>,
+    // All links are visible until the nav is measured.
+    visible: Option<usize>,
+    more: dropdown::Model,
+    observer: Option<Observer>,
+}
+
+impl Model {
+    pub fn visible_count(&self) -> Option<usize> {
+        self.visible
+    }
+}
+
+// ------ Observer ------
+
+// Measures the nav again whenever its width changes, until it's dropped.
+struct Observer {
+    observer: ResizeObserver,
+    _on_resize: Closure<dyn FnMut()>,
+}
+
+impl Observer {
+    fn new(element: &HtmlElement, on_resize: impl FnMut() + 'static) -> Option<Self> {
+        let on_resize = Closure::wrap(Box::new(on_resize) as Box<dyn FnMut()>);
+        let observer = ResizeObserver::new(on_resize.as_ref().unchecked_ref()).ok()?;
+        observer.observe(element);
+        Some(Self {
+            observer,
+            _on_resize: on_resize,
+        })
+    }
+}
+
+impl Drop for Observer {
+    fn drop(&mut self) {
+        self.observer.disconnect();
+    }
+}
+
+// ------ ------
+//    Update
+// ------ ------
+
+#[derive(Debug)]
+pub enum Msg {
+    // Send it when the links change, resizing is observed automatically.
+    Measure,
+    MoreMsg(dropdown::Msg),
+}
+
+pub fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {
+    match msg {
+        Msg::Measure => {
+            let nav = match model.nav.get() {
+                Some(nav) => nav,
+                None => return,
+            };
+            if model.observer.is_none() {
+                let (app, msg_mapper) = (orders.clone_app(), orders.msg_mapper());
+                model.observer = Observer::new(&nav, move || app.update(msg_mapper(Msg::Measure)));
+            }
+            let visible = Some(fitting_count(&nav));
+            if visible == model.visible {
+                orders.skip();
+                return;
+            }
+            model.visible = visible;
+        }
+        Msg::MoreMsg(msg) => {
+            dropdown::update(msg, &mut model.more, &mut orders.proxy(Msg::MoreMsg))
+        }
+    }
+}
+
+// Links keep their width while they are hidden, so the nav can be measured in any state.
+// The "More" dropdown takes space only when some links don't fit.
+fn fitting_count(nav: &HtmlElement) -> usize {
+    let available = nav.get_bounding_client_rect().width();
+    let widths = element_widths(nav, LINK_SELECTOR);
+    if widths.iter().sum::<f64>() <= available {
+        return widths.len();
+    }
+    let more_width = element_widths(nav, MORE_SELECTOR)
+        .first()
+        .copied()
+        .unwrap_or_default();
+    let mut used = more_width;
+    widths
+        .into_iter()
+        .take_while(|width| {
+            used += width;
+            used <= available
+        })
+        .count()
+}
+
+fn element_widths(nav: &HtmlElement, selector: &str) -> Vec<f64> {
+    let node_list = match nav.query_selector_all(selector) {
+        Ok(node_list) => node_list,
+        Err(_) => return Vec::new(),
+    };
+    (0..node_list.length())
+        .filter_map(|index| node_list.get(index))
+        .filter_map(|node| node.dyn_into::<web_sys::Element>().ok())
+        .map(|element| element.get_bounding_client_rect().width())
+        .collect()
+}
+
+// ------ ------
+//     View
+// ------ ------
+
+// ------ PriorityNav ------
+
+// A `.navbar-nav` that shows as many links as fit and lists the rest in a "More" dropdown.
+// Render it in a `NavBar` with `priority_plus` enabled.
+pub struct PriorityNav<Ms: 'static> {
+    links: Vec<NavLink<Ms>>,
+    more_title: Cow<'static, str>,
+    attrs: Attrs,
+    style: Style,
+}
+
+impl<Ms> PriorityNav<Ms> {
+    pub fn new(links: Vec<NavLink<Ms>>) -> Self {
+        Self {
+            links,
+            more_title: "More".into(),
+            attrs: Attrs::empty(),
+            style: Style::empty(),
+        }
+    }
+
+    pub fn more_title(mut self, more_title: impl Into<Cow<'static, str>>) -> Self {
+        self.more_title = more_title.into();
+        self
+    }
+
+    pub fn add_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs.merge(attrs);
+        self
+    }
+
+    pub fn add_style(mut self, style: Style) -> Self {
+        self.style.merge(style);
+        self
+    }
+
+    pub fn view(self, model: &Model, to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static) -> Node<Ms> {
+        let visible = model.visible.unwrap_or(self.links.len());
+        let overflow = &self.links[visible.min(self.links.len())..];
+
+        let more_active = overflow.iter().any(NavLink::is_active);
+        // Custom items keep the link's handlers but don't close the dropdown on their own.
+        let items = overflow
+            .iter()
+            .map(|link| {
+                let mut item = link.view_as_dropdown_item();
+                item.add_event_handler(ev(Ev::Click, {
+                    let to_msg = to_msg.clone();
+                    move |_| to_msg(Msg::MoreMsg(dropdown::Msg::Collapse(None)))
+                }));
+                Item::custom(item)
+            })
+            .collect();
+        let mut more = Dropdown::<Ms, ()>::new(self.more_title)
+            .items(items)
+            .align(Align::Right)
+            .update_toggle(|toggle| toggle.add_attrs(C![IF!(more_active => "active")]))
+            .view_in_nav(&model.more, move |msg| to_msg(Msg::MoreMsg(msg)));
+        more.add_attr("data-priority-more", AtValue::None);
+        if overflow.is_empty() {
+            hide(&mut more);
+        }
+
+        let links = self.links.into_iter().enumerate().map(|(index, link)| {
+            let mut link = link.view();
+            link.add_attr("data-priority-link", AtValue::None);
+            if index >= visible {
+                hide(&mut link);
+            }
+            link
+        });
+
+        ul![
+            el_ref(&model.nav),
+            C!["navbar-nav", "flex-nowrap"],
+            style! {
+                St::Flex => "1 1 0",
+                St::MinWidth => 0,
+            },
+            self.style,
+            self.attrs,
+            links,
+            more,
+        ]
+    }
+}
+
+// Hidden items stay in the layout out of the flow, so they can still be measured.
+fn hide<Ms>(node: &mut Node<Ms>) {
+    node.add_style(St::Position, "absolute")
+        .add_style(St::Visibility, "hidden")
+        .add_style(St::PointerEvents, "none");
+}
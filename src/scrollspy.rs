@@ -0,0 +1,156 @@
+use super::navbar::NavLink;
+use seed::{prelude::*, *};
+use std::borrow::Cow;
+
+// ------ ------
+//     Init
+// ------ ------
+
+pub fn init(orders: &mut impl Orders<Msg>) -> Model {
+    orders.after_next_render(|_| Msg::Refresh);
+    Model {
+        targets: Vec::new(),
+        offset: 10.,
+        active: None,
+        _scroll_stream: orders
+            .stream_with_handle(streams::window_event(Ev::Scroll, |_| Msg::Refresh)),
+        _resize_stream: orders
+            .stream_with_handle(streams::window_event(Ev::Resize, |_| Msg::Refresh)),
+    }
+}
+
+// ------ ------
+//     Model
+// ------ ------
+
+pub struct Model {
+    targets: Vec<String>,
+    offset: f64,
+    active: Option<String>,
+    _scroll_stream: StreamHandle,
+    _resize_stream: StreamHandle,
+}
+
+impl Model {
+    // Ids of the observed sections in the document order.
+    pub fn targets(mut self, targets: Vec<impl Into<String>>) -> Self {
+        self.targets = targets.into_iter().map(Into::into).collect();
+        self
+    }
+
+    // Distance from the viewport top, e.g. the height of a fixed navbar.
+    pub fn offset(mut self, offset: f64) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn active(&self) -> Option<&str> {
+        self.active.as_deref()
+    }
+
+    pub fn is_active(&self, target: &str) -> bool {
+        self.active() == Some(target)
+    }
+
+    // A link to the section, active while the section is in view.
+    pub fn nav_link<Ms>(&self, title: impl Into<Cow<'static, str>>, target: &str) -> NavLink<Ms> {
+        let active = self.is_active(target);
+        // The section is a location within the page, not the current page.
+        NavLink::new(title, format!("#{}", target))
+            .active(active)
+            .add_inner_attrs(attrs! {
+                At::from("aria-current") => if active { AtValue::Some("location".to_owned()) } else { AtValue::Ignored },
+            })
+    }
+}
+
+// ------ ------
+//    Update
+// ------ ------
+
+// Notified when the section in view changes, subscribe to it with `orders.subscribe`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActiveSectionChanged(pub Option<String>);
+
+#[derive(Debug)]
+pub enum Msg {
+    Refresh,
+}
+
+pub fn update(msg: Msg, model: &mut Model, orders: &mut impl Orders<Msg>) {
+    match msg {
+        Msg::Refresh => {
+            let active = find_active(&model.targets, model.offset);
+            if active == model.active {
+                orders.skip();
+                return;
+            }
+            model.active = active;
+            orders.notify(ActiveSectionChanged(model.active.clone()));
+        }
+    }
+}
+
+// The last section that has scrolled past the offset is active.
+// At the bottom of the page it's the last section, even when it's too short to reach the offset.
+fn find_active(targets: &[String], offset: f64) -> Option<String> {
+    let document = document();
+    // From the last section up.
+    let mut tops = targets.iter().rev().filter_map(|target| {
+        document
+            .get_element_by_id(target)
+            .map(|element| (target, element.get_bounding_client_rect().top()))
+    });
+    if is_scrolled_to_bottom() {
+        return tops.next().map(|(target, _)| target.clone());
+    }
+    tops.find(|(_, top)| *top <= offset + 1.)
+        .map(|(target, _)| target.clone())
+}
+
+fn is_scrolled_to_bottom() -> bool {
+    let window = window();
+    let viewport_height = window
+        .inner_height()
+        .ok()
+        .and_then(|height| height.as_f64());
+    let scroll_y = window.page_y_offset().ok();
+    let scroll_height = document()
+        .document_element()
+        .map(|root| f64::from(root.scroll_height()));
+    match (viewport_height, scroll_y, scroll_height) {
+        (Some(viewport_height), Some(scroll_y), Some(scroll_height)) => {
+            scroll_y > 0. && viewport_height + scroll_y >= scroll_height - 1.
+        }
+        _ => false,
+    }
+}
+
+// ------ ------
+//     View
+// ------ ------
+
+// A `.list-group` table of contents linking to the sections.
+pub fn view_list_group<Ms>(
+    model: &Model,
+    items: Vec<(impl Into<Cow<'static, str>>, &str)>,
+) -> Node<Ms> {
+    div![
+        C!["list-group"],
+        items.into_iter().map(|(title, target)| {
+            let active = model.is_active(target);
+            a![
+                C![
+                    "list-group-item",
+                    "list-group-item-action",
+                    IF!(active => "active")
+                ],
+                attrs! {
+                    At::Href => format!("#{}", target),
+                    At::from("aria-current") => if active { AtValue::Some("location".to_owned()) } else { AtValue::Ignored },
+                },
+                title.into(),
+            ]
+        })
+    ]
+}
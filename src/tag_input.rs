@@ -0,0 +1,441 @@
+use seed::{prelude::*, *};
+use std::{borrow::Cow, rc::Rc};
+use uuid::Uuid;
+use wasm_bindgen::JsCast;
+use web_sys::{ClipboardEvent, HtmlInputElement, KeyboardEvent};
+
+type Validator = dyn Fn(&str) -> Result<(), Cow<'static, str>>;
+
+// ------ ------
+//     Init
+// ------ ------
+
+pub fn init(tags: Vec<String>) -> Model {
+    Model {
+        tags,
+        draft: String::new(),
+        error: None,
+        validator: None,
+        separators: vec![','],
+        allow_duplicates: false,
+        input: ElRef::default(),
+        id: Uuid::new_v4().to_string(),
+    }
+}
+
+// ------ ------
+//     Model
+// ------ ------
+
+pub struct Model {
+    tags: Vec<String>,
+    draft: String,
+    error: Option<Cow<'static, str>>,
+    validator: Option<Rc<Validator>>,
+    separators: Vec<char>,
+    allow_duplicates: bool,
+    input: ElRef<HtmlInputElement>,
+    id: String,
+}
+
+impl Model {
+    pub fn validator(
+        mut self,
+        validator: impl Fn(&str) -> Result<(), Cow<'static, str>> + 'static,
+    ) -> Self {
+        self.validator = Some(Rc::new(validator));
+        self
+    }
+
+    pub fn separators(mut self, separators: Vec<char>) -> Self {
+        self.separators = separators;
+        self
+    }
+
+    pub fn allow_duplicates(mut self, allow_duplicates: bool) -> Self {
+        self.allow_duplicates = allow_duplicates;
+        self
+    }
+
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    pub fn set_tags(&mut self, tags: Vec<String>) {
+        self.tags = tags;
+        self.error = None;
+    }
+
+    fn is_separator(&self, c: char) -> bool {
+        is_separator(&self.separators, c)
+    }
+
+    // Adds every value in the draft and keeps only the rejected ones there.
+    fn commit_draft(&mut self) {
+        let draft = std::mem::take(&mut self.draft);
+        let separators = self.separators.clone();
+        let rejected = self.add_tags(draft.split(|c| is_separator(&separators, c)));
+        self.draft = self.join_draft(&rejected);
+    }
+
+    // Joins values left in the draft so that they are split again on the next commit.
+    fn join_draft(&self, values: &[&str]) -> String {
+        match self.separators.first() {
+            Some(separator) => values.join(&format!("{} ", separator)),
+            None => values.join(" "),
+        }
+    }
+
+    // Adds every valid candidate and returns the rejected ones.
+    fn add_tags<'a>(&mut self, candidates: impl Iterator<Item = &'a str>) -> Vec<&'a str> {
+        self.error = None;
+        let mut rejected = Vec::new();
+        for candidate in candidates.map(str::trim).filter(|c| !c.is_empty()) {
+            if !self.allow_duplicates && self.tags.iter().any(|tag| tag == candidate) {
+                continue;
+            }
+            if let Some(validator) = &self.validator {
+                if let Err(error) = validator(candidate) {
+                    if self.error.is_none() {
+                        self.error = Some(error);
+                    }
+                    rejected.push(candidate);
+                    continue;
+                }
+            }
+            self.tags.push(candidate.to_owned());
+        }
+        rejected
+    }
+}
+
+// ------ ------
+//    Update
+// ------ ------
+
+#[derive(Debug)]
+pub enum Msg {
+    DraftChanged(String),
+    Commit,
+    Remove(usize),
+    RemoveLast,
+    FocusInput,
+}
+
+pub fn update(msg: Msg, model: &mut Model, _: &mut impl Orders<Msg>) {
+    match msg {
+        Msg::DraftChanged(draft) => match draft.rfind(|c| model.is_separator(c)) {
+            Some(last_separator) => {
+                let separator_len = draft[last_separator..]
+                    .chars()
+                    .next()
+                    .map_or(0, char::len_utf8);
+                let rest = &draft[last_separator + separator_len..];
+                let candidates = draft[..last_separator]
+                    .split(|c| model.is_separator(c))
+                    .collect::<Vec<_>>();
+                let mut remaining = model.add_tags(candidates.into_iter());
+                if !rest.trim().is_empty() {
+                    remaining.push(rest.trim_start());
+                }
+                model.draft = model.join_draft(&remaining);
+            }
+            None => {
+                model.draft = draft;
+                model.error = None;
+            }
+        },
+        Msg::Commit => model.commit_draft(),
+        Msg::Remove(index) => {
+            if index < model.tags.len() {
+                model.tags.remove(index);
+            }
+        }
+        Msg::RemoveLast => {
+            model.tags.pop();
+        }
+        Msg::FocusInput => {
+            if let Some(input) = model.input.get() {
+                let _ = input.focus();
+            }
+        }
+    }
+}
+
+// ------ ------
+//     View
+// ------ ------
+
+// ------ TagInput ------
+
+pub struct TagInput {
+    id: Option<Cow<'static, str>>,
+    placeholder: Option<Cow<'static, str>>,
+    suggestions: Vec<Cow<'static, str>>,
+    badge_class: Cow<'static, str>,
+    attrs: Attrs,
+    input_attrs: Attrs,
+}
+
+impl TagInput {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn id(mut self, id: impl Into<Cow<'static, str>>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<Cow<'static, str>>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    pub fn suggestions(mut self, suggestions: Vec<impl Into<Cow<'static, str>>>) -> Self {
+        self.suggestions = suggestions.into_iter().map(Into::into).collect();
+        self
+    }
+
+    pub fn badge_class(mut self, badge_class: impl Into<Cow<'static, str>>) -> Self {
+        self.badge_class = badge_class.into();
+        self
+    }
+
+    pub fn add_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs.merge(attrs);
+        self
+    }
+
+    pub fn add_input_attrs(mut self, attrs: Attrs) -> Self {
+        self.input_attrs.merge(attrs);
+        self
+    }
+
+    pub fn view<Ms: 'static>(
+        self,
+        model: &Model,
+        to_msg: impl FnOnce(Msg) -> Ms + Clone + 'static,
+    ) -> Node<Ms> {
+        let to_msg = move |msg| to_msg.clone()(msg);
+        let id = self.id.unwrap_or_else(|| model.id.clone().into());
+        let datalist_id = format!("{}-suggestions", id);
+        let draft_is_empty = model.draft.is_empty();
+        let separators = model.separators.clone();
+        let badge_class = self.badge_class;
+
+        div![
+            div![
+                C![
+                    "form-control",
+                    "d-flex",
+                    "flex-wrap",
+                    "align-items-center",
+                    "h-auto",
+                    IF!(model.error.is_some() => "is-invalid")
+                ],
+                self.attrs,
+                ev(Ev::Click, {
+                    let to_msg = to_msg.clone();
+                    move |_| to_msg(Msg::FocusInput)
+                }),
+                model.tags.iter().enumerate().map(|(index, tag)| {
+                    span![
+                        C!["badge", badge_class.as_ref(), "mr-1", "my-1"],
+                        tag,
+                        button![
+                            C!["close", "ml-1"],
+                            style! {St::FontSize => "inherit"},
+                            attrs! {
+                                At::Type => "button",
+                                At::from("aria-label") => format!("Remove {}", tag),
+                            },
+                            span![attrs! {At::from("aria-hidden") => "true"}, "×"],
+                            ev(Ev::Click, {
+                                let to_msg = to_msg.clone();
+                                move |event| {
+                                    event.stop_propagation();
+                                    to_msg(Msg::Remove(index))
+                                }
+                            }),
+                        ],
+                    ]
+                }),
+                input![
+                    el_ref(&model.input),
+                    C!["border-0", "flex-grow-1", "my-1"],
+                    style! {St::Outline => "none", St::MinWidth => rem(6)},
+                    id!(id),
+                    attrs! {
+                        At::Type => "text",
+                        At::Value => model.draft,
+                        At::AutoComplete => "off",
+                        At::Placeholder => match &self.placeholder {
+                            Some(placeholder) => AtValue::Some(placeholder.to_string()),
+                            None => AtValue::Ignored,
+                        },
+                        At::List => if self.suggestions.is_empty() {
+                            AtValue::Ignored
+                        } else {
+                            AtValue::Some(datalist_id.clone())
+                        },
+                        At::from("aria-invalid") => model.error.is_some(),
+                    },
+                    self.input_attrs,
+                    input_ev(Ev::Input, {
+                        let to_msg = to_msg.clone();
+                        move |draft| to_msg(Msg::DraftChanged(draft))
+                    }),
+                    ev(Ev::Paste, {
+                        let to_msg = to_msg.clone();
+                        move |event| {
+                            let text = event
+                                .dyn_ref::<ClipboardEvent>()
+                                .and_then(ClipboardEvent::clipboard_data)
+                                .and_then(|data| data.get_data("text").ok())?;
+                            // Text without separators is pasted into the draft by the browser.
+                            if !text.contains(|c| is_separator(&separators, c)) {
+                                return None;
+                            }
+                            let input = event.target()?.dyn_into::<HtmlInputElement>().ok()?;
+                            let draft = input.value();
+                            let start = input.selection_start().ok().flatten();
+                            let end = input.selection_end().ok().flatten();
+                            let (start, end) = match (start, end) {
+                                (Some(start), Some(end)) => {
+                                    (byte_index(&draft, start), byte_index(&draft, end))
+                                }
+                                _ => (draft.len(), draft.len()),
+                            };
+                            event.prevent_default();
+                            let draft = format!("{}{}{}", &draft[..start], text, &draft[end..]);
+                            Some(to_msg(Msg::DraftChanged(draft)))
+                        }
+                    }),
+                    keyboard_ev(Ev::KeyDown, move |event: KeyboardEvent| {
+                        let msg = match event.key().as_str() {
+                            "Enter" => Msg::Commit,
+                            "Backspace" if draft_is_empty => Msg::RemoveLast,
+                            _ => return None,
+                        };
+                        event.prevent_default();
+                        Some(to_msg(msg))
+                    }),
+                ],
+            ],
+            if self.suggestions.is_empty() {
+                empty![]
+            } else {
+                datalist![
+                    id!(datalist_id),
+                    self.suggestions
+                        .iter()
+                        .filter(|suggestion| !model
+                            .tags
+                            .iter()
+                            .any(|tag| tag == suggestion.as_ref()))
+                        .map(|suggestion| option![attrs! {At::Value => suggestion}]),
+                ]
+            },
+            model
+                .error
+                .as_ref()
+                .map(|error| div![C!["invalid-feedback"], error]),
+        ]
+    }
+}
+
+impl Default for TagInput {
+    fn default() -> Self {
+        Self {
+            id: None,
+            placeholder: None,
+            suggestions: Vec::new(),
+            badge_class: "badge-secondary".into(),
+            attrs: Attrs::empty(),
+            input_attrs: Attrs::empty(),
+        }
+    }
+}
+
+fn is_separator(separators: &[char], c: char) -> bool {
+    c == '\n' || c == '\r' || separators.contains(&c)
+}
+
+// Converts a caret position in UTF-16 code units, as reported by the input, to a byte index.
+fn byte_index(text: &str, utf16_index: u32) -> usize {
+    let mut units = 0;
+    for (index, c) in text.char_indices() {
+        if units >= utf16_index as usize {
+            return index;
+        }
+        units += c.len_utf16();
+    }
+    text.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn no_bad(tag: &str) -> Result<(), Cow<'static, str>> {
+        if tag.starts_with("bad") {
+            Err("Bad tag".into())
+        } else {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn add_tags_skips_empty_and_duplicate_candidates() {
+        let mut model = init(vec!["a".to_owned()]);
+        let rejected = model.add_tags(vec![" b ", "", "a", "  ", "b"].into_iter());
+        assert!(rejected.is_empty());
+        assert_eq!(model.tags(), ["a", "b"]);
+        assert!(model.error.is_none());
+    }
+
+    #[test]
+    fn add_tags_returns_rejected_candidates() {
+        let mut model = init(Vec::new()).validator(no_bad);
+        let rejected = model.add_tags(vec!["bad1", "good", "bad2"].into_iter());
+        assert_eq!(rejected, ["bad1", "bad2"]);
+        assert_eq!(model.tags(), ["good"]);
+        assert_eq!(model.error.as_deref(), Some("Bad tag"));
+    }
+
+    #[test]
+    fn commit_splits_draft_on_separators() {
+        let mut model = init(Vec::new());
+        model.draft = "one, two,three".to_owned();
+        model.commit_draft();
+        assert_eq!(model.tags(), ["one", "two", "three"]);
+        assert_eq!(model.draft, "");
+    }
+
+    #[test]
+    fn commit_keeps_rejected_values_split_by_first_separator() {
+        let mut model = init(Vec::new())
+            .validator(no_bad)
+            .separators(vec![';', ',']);
+        model.draft = "bad1; good1;bad2".to_owned();
+        model.commit_draft();
+        assert_eq!(model.tags(), ["good1"]);
+        assert_eq!(model.draft, "bad1; bad2");
+
+        model.validator = None;
+        model.commit_draft();
+        assert_eq!(model.tags(), ["good1", "bad1", "bad2"]);
+    }
+
+    #[test]
+    fn byte_index_counts_utf16_units() {
+        assert_eq!(byte_index("abc", 0), 0);
+        assert_eq!(byte_index("abc", 2), 2);
+        assert_eq!(byte_index("abc", 9), 3);
+        // "é" is 2 bytes and 1 unit, "😀" is 4 bytes and 2 units.
+        assert_eq!(byte_index("é😀x", 1), 2);
+        assert_eq!(byte_index("é😀x", 3), 6);
+        assert_eq!(byte_index("é😀x", 4), 7);
+    }
+}
@@ -0,0 +1,553 @@
+use super::dismiss::{self, DismissHandle};
+use super::popper::{PopperData, PopperOptions};
+use seed::{prelude::*, *};
+use std::{borrow::Cow, fmt::Display, future::Future, ops::Range, pin::Pin, rc::Rc};
+use uuid::Uuid;
+use wasm_bindgen::JsCast;
+use web_sys::{EventTarget, HtmlElement, KeyboardEvent, MouseEvent};
+
+const DEFAULT_DEBOUNCE_MS: u32 = 300;
+
+// ------ ------
+//     Init
+// ------ ------
+
+pub fn init<T: 'static>(source: Source<T>, _: &mut impl Orders<Msg<T>>) -> Model<T> {
+    Model {
+        query: String::new(),
+        suggestions: Suggestions::Idle,
+        highlighted: None,
+        selected: None,
+        expanded: false,
+        input: ElRef::default(),
+        popup: ElRef::default(),
+        popup_style: String::new(),
+        id: Uuid::new_v4().to_string(),
+        source,
+        debounce_ms: DEFAULT_DEBOUNCE_MS,
+        min_length: 1,
+        popper_data: None,
+        search_handle: None,
+        dismiss_handle: None,
+    }
+}
+
+// ------ ------
+//     Model
+// ------ ------
+
+pub struct Model<T> {
+    query: String,
+    suggestions: Suggestions<T>,
+    highlighted: Option<usize>,
+    selected: Option<T>,
+    expanded: bool,
+    input: ElRef<HtmlElement>,
+    popup: ElRef<HtmlElement>,
+    popup_style: String,
+    id: String,
+    source: Source<T>,
+    debounce_ms: u32,
+    min_length: usize,
+    popper_data: Option<PopperData>,
+    search_handle: Option<CmdHandle>,
+    dismiss_handle: Option<DismissHandle>,
+}
+
+impl<T> Model<T> {
+    pub fn debounce(mut self, ms: u32) -> Self {
+        self.debounce_ms = ms;
+        self
+    }
+
+    pub fn min_length(mut self, min_length: usize) -> Self {
+        self.min_length = min_length;
+        self
+    }
+
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    pub fn selected(&self) -> Option<&T> {
+        self.selected.as_ref()
+    }
+
+    fn suggestion_count(&self) -> usize {
+        match &self.suggestions {
+            Suggestions::Loaded(suggestions) => suggestions.len(),
+            Suggestions::Idle | Suggestions::Loading => 0,
+        }
+    }
+}
+
+// ------ Source ------
+
+type SuggestionsFn<T> = dyn Fn(&str) -> Vec<T>;
+type SuggestionsFuture<T> = Pin<Box<dyn Future<Output = Vec<T>>>>;
+
+pub enum Source<T> {
+    Closure(Rc<SuggestionsFn<T>>),
+    Future(Rc<dyn Fn(String) -> SuggestionsFuture<T>>),
+}
+
+impl<T> Source<T> {
+    pub fn closure(source: impl Fn(&str) -> Vec<T> + 'static) -> Self {
+        Self::Closure(Rc::new(source))
+    }
+
+    pub fn future<F: Future<Output = Vec<T>> + 'static>(
+        source: impl Fn(String) -> F + 'static,
+    ) -> Self {
+        Self::Future(Rc::new(move |query| Box::pin(source(query))))
+    }
+}
+
+// ------ Suggestions ------
+
+enum Suggestions<T> {
+    Idle,
+    Loading,
+    Loaded(Vec<T>),
+}
+
+// ------ ------
+//    Update
+// ------ ------
+
+#[derive(Debug)]
+pub enum Msg<T> {
+    InputChanged(String),
+    Search(String),
+    SuggestionsLoaded(String, Vec<T>),
+    Focused,
+    HighlightNext,
+    HighlightPrevious,
+    Highlight(usize),
+    Select(usize),
+    UpdatePopper,
+    Collapse(Option<EventTarget>),
+    OnApplyStyles(String),
+}
+
+pub fn update<T: Display + Clone + 'static>(
+    msg: Msg<T>,
+    model: &mut Model<T>,
+    orders: &mut impl Orders<Msg<T>>,
+) {
+    match msg {
+        Msg::InputChanged(query) => {
+            model.query = query;
+            model.selected = None;
+            model.highlighted = None;
+            if model.query.chars().count() < model.min_length {
+                model.search_handle = None;
+                model.suggestions = Suggestions::Idle;
+                collapse(model);
+            } else {
+                let query = model.query.clone();
+                model.search_handle = Some(
+                    orders.perform_cmd_with_handle(cmds::timeout(model.debounce_ms, move || {
+                        Msg::<T>::Search(query)
+                    })),
+                );
+            }
+        }
+        Msg::Search(query) => {
+            if query != model.query {
+                return;
+            }
+            match &model.source {
+                Source::Closure(source) => {
+                    model.suggestions = Suggestions::Loaded(source(&query));
+                    model.search_handle = None;
+                }
+                Source::Future(source) => {
+                    let suggestions = source(query.clone());
+                    model.suggestions = Suggestions::Loading;
+                    model.search_handle = Some(orders.perform_cmd_with_handle(async move {
+                        Msg::SuggestionsLoaded(query, suggestions.await)
+                    }));
+                }
+            }
+            expand(model, orders);
+        }
+        Msg::SuggestionsLoaded(query, suggestions) => {
+            if query == model.query {
+                model.suggestions = Suggestions::Loaded(suggestions);
+                model.highlighted = None;
+                model.search_handle = None;
+                orders.after_next_render(|_| Msg::<T>::UpdatePopper);
+            }
+        }
+        Msg::Focused => {
+            if !model.expanded && matches!(model.suggestions, Suggestions::Loaded(_)) {
+                expand(model, orders);
+            }
+        }
+        Msg::HighlightNext => {
+            // Arrow down opens the popup again after it was closed with Esc.
+            if !model.expanded {
+                if !matches!(model.suggestions, Suggestions::Loaded(_)) {
+                    return;
+                }
+                expand(model, orders);
+            }
+            let count = model.suggestion_count();
+            if count > 0 {
+                model.highlighted = Some(match model.highlighted {
+                    Some(index) if index + 1 < count => index + 1,
+                    Some(_) => count - 1,
+                    None => 0,
+                });
+            }
+        }
+        Msg::HighlightPrevious => {
+            if !model.expanded {
+                orders.skip();
+                return;
+            }
+            model.highlighted = match model.highlighted {
+                Some(index) if index > 0 => Some(index - 1),
+                _ => None,
+            };
+        }
+        Msg::Highlight(index) => model.highlighted = Some(index),
+        Msg::Select(index) => {
+            if let Suggestions::Loaded(suggestions) = &model.suggestions {
+                if let Some(suggestion) = suggestions.get(index) {
+                    model.query = suggestion.to_string();
+                    model.selected = Some(suggestion.clone());
+                    model.highlighted = None;
+                    model.search_handle = None;
+                    collapse(model);
+                }
+            }
+        }
+        Msg::UpdatePopper => {
+            if model.expanded {
+                PopperData::update_or_show(
+                    &mut model.popper_data,
+                    &model.input,
+                    &model.popup,
+                    PopperOptions::default(),
+                    orders,
+                    Msg::<T>::OnApplyStyles,
+                );
+            }
+        }
+        Msg::Collapse(event_target) => {
+            if model.expanded {
+                if let Some(target) =
+                    event_target.and_then(|target| target.dyn_into::<web_sys::Node>().ok())
+                {
+                    let is_target_inside = model
+                        .input
+                        .get()
+                        .into_iter()
+                        .chain(model.popup.get())
+                        .any(|element| element.contains(Some(&target)));
+                    if is_target_inside {
+                        return;
+                    }
+                }
+                collapse(model);
+                model.highlighted = None;
+            }
+        }
+        Msg::OnApplyStyles(popup_style) => model.popup_style = popup_style,
+    }
+}
+
+fn collapse<T>(model: &mut Model<T>) {
+    model.expanded = false;
+    model.dismiss_handle = None;
+}
+
+fn expand<T: 'static>(model: &mut Model<T>, orders: &mut impl Orders<Msg<T>>) {
+    model.expanded = true;
+    if model.dismiss_handle.is_none() {
+        let (app, msg_mapper) = (orders.clone_app(), orders.msg_mapper());
+        model.dismiss_handle = Some(dismiss::on_dismiss(
+            vec![model.input.clone(), model.popup.clone()],
+            move || app.update(msg_mapper(Msg::<T>::Collapse(None))),
+        ));
+    }
+    if model.popper_data.is_none() {
+        // Reported by `Msg::UpdatePopper` if the refs still aren't mounted after the render.
+        let _ = PopperData::show(
+            &mut model.popper_data,
+            &model.input,
+            &model.popup,
+            PopperOptions::default(),
+            orders,
+            Msg::<T>::OnApplyStyles,
+        );
+    }
+    orders.after_next_render(|_| Msg::<T>::UpdatePopper);
+}
+
+// ------ ------
+//     View
+// ------ ------
+
+// ------ Typeahead ------
+
+pub struct Typeahead<Ms: 'static, T> {
+    id: Option<Cow<'static, str>>,
+    placeholder: Option<Cow<'static, str>>,
+    loading_text: Cow<'static, str>,
+    no_results_text: Cow<'static, str>,
+    on_selects: Vec<Rc<dyn Fn(T) -> Ms>>,
+    attrs: Attrs,
+    input_attrs: Attrs,
+}
+
+impl<Ms: 'static, T: Display + Clone + 'static> Typeahead<Ms, T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn id(mut self, id: impl Into<Cow<'static, str>>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    pub fn placeholder(mut self, placeholder: impl Into<Cow<'static, str>>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    pub fn loading_text(mut self, loading_text: impl Into<Cow<'static, str>>) -> Self {
+        self.loading_text = loading_text.into();
+        self
+    }
+
+    pub fn no_results_text(mut self, no_results_text: impl Into<Cow<'static, str>>) -> Self {
+        self.no_results_text = no_results_text.into();
+        self
+    }
+
+    pub fn add_on_select(mut self, on_select: impl FnOnce(T) -> Ms + Clone + 'static) -> Self {
+        self.on_selects
+            .push(Rc::new(move |value| on_select.clone()(value)));
+        self
+    }
+
+    pub fn add_attrs(mut self, attrs: Attrs) -> Self {
+        self.attrs.merge(attrs);
+        self
+    }
+
+    pub fn add_input_attrs(mut self, attrs: Attrs) -> Self {
+        self.input_attrs.merge(attrs);
+        self
+    }
+
+    pub fn view(
+        self,
+        model: &Model<T>,
+        to_msg: impl FnOnce(Msg<T>) -> Ms + Clone + 'static,
+    ) -> Node<Ms> {
+        let to_msg = move |msg| to_msg.clone()(msg);
+        let id = self.id.unwrap_or_else(|| model.id.clone().into());
+        let on_selects = self.on_selects;
+        let menu_id = format!("{}-menu", id);
+        let option_id = |index: usize| format!("{}-option-{}", id, index);
+        let suggestions = match &model.suggestions {
+            Suggestions::Loaded(suggestions) => suggestions.as_slice(),
+            Suggestions::Idle | Suggestions::Loading => &[],
+        };
+        // Suggestions in a closed popup can't be selected.
+        let highlighted = model
+            .highlighted
+            .filter(|_| model.expanded)
+            .and_then(|index| suggestions.get(index).map(|value| (index, value.clone())));
+
+        let mut input = input![
+            el_ref(&model.input),
+            C!["form-control"],
+            id!(id.clone()),
+            attrs! {
+                At::Type => "text",
+                At::Value => model.query,
+                At::AutoComplete => "off",
+                At::Placeholder => match &self.placeholder {
+                    Some(placeholder) => AtValue::Some(placeholder.to_string()),
+                    None => AtValue::Ignored,
+                },
+                At::from("role") => "combobox",
+                At::from("aria-autocomplete") => "list",
+                At::from("aria-expanded") => model.expanded,
+                At::from("aria-controls") => menu_id,
+                At::from("aria-activedescendant") => match &highlighted {
+                    Some((index, _)) => AtValue::Some(option_id(*index)),
+                    None => AtValue::Ignored,
+                },
+            },
+            self.input_attrs,
+            input_ev(Ev::Input, {
+                let to_msg = to_msg.clone();
+                move |value| to_msg(Msg::InputChanged(value))
+            }),
+            ev(Ev::Focus, {
+                let to_msg = to_msg.clone();
+                move |_| to_msg(Msg::Focused)
+            }),
+            keyboard_ev(Ev::KeyDown, {
+                let to_msg = to_msg.clone();
+                let highlighted = highlighted.as_ref().map(|(index, _)| *index);
+                move |event| {
+                    let msg = match event.key().as_str() {
+                        // Focus moves on, only the popup is closed.
+                        "Tab" => return Some(to_msg(Msg::Collapse(None))),
+                        "ArrowDown" => Msg::HighlightNext,
+                        "ArrowUp" => Msg::HighlightPrevious,
+                        "Enter" => Msg::Select(highlighted?),
+                        "Escape" => Msg::Collapse(None),
+                        _ => return None,
+                    };
+                    event.prevent_default();
+                    Some(to_msg(msg))
+                }
+            }),
+        ];
+        if let Some((_, value)) = highlighted {
+            for on_select in on_selects.clone() {
+                input.add_event_handler(keyboard_ev(Ev::KeyDown, {
+                    let value = value.clone();
+                    move |event: KeyboardEvent| {
+                        if event.key() == "Enter" {
+                            Some(on_select(value))
+                        } else {
+                            None
+                        }
+                    }
+                }));
+            }
+        }
+
+        div![
+            C!["dropdown"],
+            self.attrs,
+            input,
+            div![
+                el_ref(&model.popup),
+                C!["dropdown-menu", IF!(model.expanded => "show")],
+                id!(menu_id),
+                attrs! {
+                    At::Style => model.popup_style,
+                    At::from("role") => "listbox",
+                    At::from("aria-labelledby") => id,
+                },
+                match &model.suggestions {
+                    Suggestions::Idle => vec![],
+                    Suggestions::Loading => vec![span![
+                        C!["dropdown-item-text", "text-muted"],
+                        self.loading_text
+                    ]],
+                    Suggestions::Loaded(suggestions) if suggestions.is_empty() => vec![span![
+                        C!["dropdown-item-text", "text-muted"],
+                        self.no_results_text
+                    ]],
+                    Suggestions::Loaded(suggestions) => suggestions
+                        .iter()
+                        .enumerate()
+                        .map(|(index, suggestion)| {
+                            view_suggestion(
+                                index,
+                                suggestion,
+                                &model.query,
+                                model.highlighted == Some(index),
+                                option_id(index),
+                                to_msg.clone(),
+                                &on_selects,
+                            )
+                        })
+                        .collect(),
+                },
+            ],
+        ]
+    }
+}
+
+impl<Ms, T> Default for Typeahead<Ms, T> {
+    fn default() -> Self {
+        Self {
+            id: None,
+            placeholder: None,
+            loading_text: "Loading...".into(),
+            no_results_text: "No results".into(),
+            on_selects: Vec::new(),
+            attrs: Attrs::empty(),
+            input_attrs: Attrs::empty(),
+        }
+    }
+}
+
+fn view_suggestion<Ms: 'static, T: Display + Clone + 'static>(
+    index: usize,
+    suggestion: &T,
+    query: &str,
+    highlighted: bool,
+    option_id: String,
+    to_msg: impl Fn(Msg<T>) -> Ms + Clone + 'static,
+    on_selects: &[Rc<dyn Fn(T) -> Ms>],
+) -> Node<Ms> {
+    let title = suggestion.to_string();
+    let mut node = button![
+        C!["dropdown-item", IF!(highlighted => "active")],
+        id!(option_id),
+        attrs! {
+            At::Type => "button",
+            At::TabIndex => -1,
+            At::from("role") => "option",
+            At::from("aria-selected") => highlighted,
+        },
+        match match_range(&title, query) {
+            Some(range) => nodes![
+                plain!(title[..range.start].to_owned()),
+                strong![&title[range.clone()]],
+                plain!(title[range.end..].to_owned()),
+            ],
+            None => nodes![plain!(title.clone())],
+        },
+        ev(Ev::MouseEnter, {
+            let to_msg = to_msg.clone();
+            move |_| to_msg(Msg::Highlight(index))
+        }),
+        // `mousedown` fires before the input loses focus.
+        mouse_ev(Ev::MouseDown, move |event| {
+            event.prevent_default();
+            to_msg(Msg::Select(index))
+        }),
+    ];
+    for on_select in on_selects.iter().cloned() {
+        node.add_event_handler(mouse_ev(Ev::MouseDown, {
+            let value = suggestion.clone();
+            move |_: MouseEvent| on_select(value)
+        }));
+    }
+    node
+}
+
+fn match_range(title: &str, query: &str) -> Option<Range<usize>> {
+    let query = query.trim();
+    if query.is_empty() {
+        return None;
+    }
+    let lowercase_title = title.to_lowercase();
+    let range = if lowercase_title.len() == title.len() {
+        let lowercase_query = query.to_lowercase();
+        let start = lowercase_title.find(&lowercase_query)?;
+        start..start + lowercase_query.len()
+    } else {
+        let start = title.find(query)?;
+        start..start + query.len()
+    };
+    if title.is_char_boundary(range.start) && title.is_char_boundary(range.end) {
+        Some(range)
+    } else {
+        None
+    }
+}
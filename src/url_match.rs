@@ -0,0 +1,113 @@
+use seed::prelude::*;
+
+// ------ UrlMatch ------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UrlMatch {
+    // The link path equals the URL path.
+    Exact,
+    // The link path is the URL path or one of its ancestors, e.g. `/docs` for `/docs/intro`.
+    // The root link `/` matches only the root URL.
+    Prefix,
+}
+
+impl UrlMatch {
+    // Links to in-page anchors like `#` never match.
+    pub(crate) fn matches(self, link: &str, url: &Url) -> bool {
+        if link.starts_with('#') {
+            return false;
+        }
+        let link_path = link_path(link).collect::<Vec<_>>();
+        let url_path = url.path().iter().filter(|segment| !segment.is_empty());
+        match self {
+            Self::Exact => url_path.eq(link_path),
+            Self::Prefix => {
+                let url_path = url_path.collect::<Vec<_>>();
+                if link_path.is_empty() {
+                    return url_path.is_empty();
+                }
+                url_path.len() >= link_path.len()
+                    && link_path
+                        .iter()
+                        .zip(url_path)
+                        .all(|(link_segment, url_segment)| link_segment == url_segment)
+            }
+        }
+    }
+}
+
+// Path segments of a relative or absolute link, without the query and the hash.
+fn link_path(link: &str) -> impl Iterator<Item = &str> {
+    let link = match link.find("://") {
+        Some(scheme_end) => {
+            let authority = &link[scheme_end + 3..];
+            authority
+                .find('/')
+                .map_or("", |path_start| &authority[path_start..])
+        }
+        None => link,
+    };
+    let path_end = link.find(&['?', '#'][..]).unwrap_or(link.len());
+    link[..path_end]
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn url(path: &[&str]) -> Url {
+        Url::new().set_path(path)
+    }
+
+    #[test]
+    fn link_path_ignores_slashes_query_and_hash() {
+        let segments = |link| link_path(link).collect::<Vec<_>>();
+        assert!(segments("/").is_empty());
+        assert!(segments("").is_empty());
+        assert_eq!(segments("/docs/"), ["docs"]);
+        assert_eq!(segments("docs//intro"), ["docs", "intro"]);
+        assert_eq!(segments("/docs?page=2#top"), ["docs"]);
+        assert_eq!(segments("/docs#top"), ["docs"]);
+        assert_eq!(
+            segments("https://example.com/docs/intro"),
+            ["docs", "intro"]
+        );
+        assert!(segments("https://example.com").is_empty());
+    }
+
+    #[test]
+    fn exact_matches_only_the_same_path() {
+        assert!(UrlMatch::Exact.matches("/docs", &url(&["docs"])));
+        assert!(UrlMatch::Exact.matches("/docs/", &url(&["docs", ""])));
+        assert!(UrlMatch::Exact.matches("/docs?page=2", &url(&["docs"])));
+        assert!(UrlMatch::Exact.matches("/", &url(&[])));
+        assert!(!UrlMatch::Exact.matches("/docs", &url(&["docs", "intro"])));
+        assert!(!UrlMatch::Exact.matches("/", &url(&["docs"])));
+    }
+
+    #[test]
+    fn prefix_matches_ancestors() {
+        assert!(UrlMatch::Prefix.matches("/docs", &url(&["docs"])));
+        assert!(UrlMatch::Prefix.matches("/docs/", &url(&["docs", "intro"])));
+        assert!(UrlMatch::Prefix.matches("/docs#top", &url(&["docs", "intro"])));
+        assert!(!UrlMatch::Prefix.matches("/docs/intro", &url(&["docs"])));
+        assert!(!UrlMatch::Prefix.matches("/doc", &url(&["docs"])));
+    }
+
+    #[test]
+    fn prefix_root_matches_only_root() {
+        assert!(UrlMatch::Prefix.matches("/", &url(&[])));
+        assert!(UrlMatch::Prefix.matches("/", &url(&[""])));
+        assert!(!UrlMatch::Prefix.matches("/", &url(&["docs"])));
+        assert!(!UrlMatch::Prefix.matches("/?tab=1", &url(&["docs"])));
+    }
+
+    #[test]
+    fn anchor_links_never_match() {
+        assert!(!UrlMatch::Exact.matches("#", &url(&[])));
+        assert!(!UrlMatch::Prefix.matches("#", &url(&[])));
+        assert!(!UrlMatch::Prefix.matches("#section", &url(&["docs"])));
+    }
+}